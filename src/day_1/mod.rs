@@ -27,7 +27,7 @@ fn extract_number(line: &str, word_to_digit: &HashMap<String, char>) -> String {
     let mut current_word = String::new();
 
     for char in line.chars() {
-        if char.is_digit(10) {
+        if char.is_ascii_digit() {
             result.push(char);
             current_word.clear();
         } else if char.is_alphabetic() {
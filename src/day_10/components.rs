@@ -0,0 +1,195 @@
+use crate::day_10::enclosed_area::{enclosed_tiles_by_pick, polygon_area_and_length};
+use crate::day_10::explore::Explorer;
+use crate::day_10::pipe::{opposite, DIRECTIONS};
+use crate::day_10::pipe_grid::PipeGrid;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ComponentKind {
+    ClosedLoop { enclosed_tiles: usize },
+    DeadEndChain,
+    IsolatedPipe,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PipeComponent {
+    pub kind: ComponentKind,
+    pub positions: Vec<(usize, usize)>,
+    pub bounding_box: ((usize, usize), (usize, usize)),
+}
+
+impl PipeComponent {
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    pub fn is_closed_loop(&self) -> bool {
+        matches!(self.kind, ComponentKind::ClosedLoop { .. })
+    }
+
+    pub fn contains(&self, position: (usize, usize)) -> bool {
+        let ((min_x, min_y), (max_x, max_y)) = self.bounding_box;
+        let (x, y) = position;
+        (min_x..=max_x).contains(&x) && (min_y..=max_y).contains(&y) && self.positions.contains(&position)
+    }
+}
+
+impl Explorer {
+    pub fn pipe_components(&self) -> Vec<PipeComponent> {
+        find_pipe_components(self.grid())
+    }
+
+    pub fn largest_enclosing_loop(&self) -> Option<PipeComponent> {
+        self.pipe_components().into_iter()
+            .filter_map(|component| match component.kind {
+                ComponentKind::ClosedLoop { enclosed_tiles } => Some((enclosed_tiles, component)),
+                _ => None,
+            })
+            .max_by_key(|(enclosed_tiles, _)| *enclosed_tiles)
+            .map(|(_, component)| component)
+    }
+}
+
+pub fn find_pipe_components(grid: &PipeGrid) -> Vec<PipeComponent> {
+    let mut visited = vec![false; grid.len()];
+    let mut components = Vec::new();
+
+    for position in grid.positions() {
+        if visited[grid.index(position)] || grid.connections(position) == 0 {
+            continue;
+        }
+
+        let (first_position, is_closed) = find_chain_end(grid, position);
+        let positions = walk_chain(grid, first_position, &mut visited);
+        let kind = if is_closed {
+            let (twice_area, loop_length) = polygon_area_and_length(positions.iter().copied());
+            ComponentKind::ClosedLoop { enclosed_tiles: enclosed_tiles_by_pick(twice_area, loop_length) }
+        } else if positions.len() == 1 {
+            ComponentKind::IsolatedPipe
+        } else {
+            ComponentKind::DeadEndChain
+        };
+
+        components.push(PipeComponent { kind, bounding_box: bounding_box(&positions), positions });
+    }
+
+    components
+}
+
+fn linked_neighbours(grid: &PipeGrid, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+    let connections = grid.connections(position);
+    DIRECTIONS.into_iter()
+        .filter(move |&direction| connections & direction != 0)
+        .filter_map(move |direction| {
+            grid.neighbour(position, direction)
+                .filter(|&neighbour| grid.connections(neighbour) & opposite(direction) != 0)
+        })
+}
+
+fn next_in_chain(grid: &PipeGrid, previous_position: Option<(usize, usize)>, current_position: (usize, usize)) -> Option<(usize, usize)> {
+    linked_neighbours(grid, current_position).find(|&neighbour| Some(neighbour) != previous_position)
+}
+
+fn find_chain_end(grid: &PipeGrid, position: (usize, usize)) -> ((usize, usize), bool) {
+    let mut previous_position = None;
+    let mut current_position = position;
+
+    while let Some(next_position) = next_in_chain(grid, previous_position, current_position) {
+        if next_position == position {
+            return (position, true);
+        }
+        previous_position = Some(current_position);
+        current_position = next_position;
+    }
+
+    (current_position, false)
+}
+
+fn walk_chain(grid: &PipeGrid, first_position: (usize, usize), visited: &mut [bool]) -> Vec<(usize, usize)> {
+    let mut positions = vec![first_position];
+    visited[grid.index(first_position)] = true;
+    let mut previous_position = None;
+    let mut current_position = first_position;
+
+    while let Some(next_position) = next_in_chain(grid, previous_position, current_position) {
+        if next_position == first_position {
+            break;
+        }
+        positions.push(next_position);
+        visited[grid.index(next_position)] = true;
+        previous_position = Some(current_position);
+        current_position = next_position;
+    }
+
+    positions
+}
+
+fn bounding_box(positions: &[(usize, usize)]) -> ((usize, usize), (usize, usize)) {
+    positions.iter().fold(((usize::MAX, usize::MAX), (0, 0)), |((min_x, min_y), (max_x, max_y)), &(x, y)| {
+        ((min_x.min(x), min_y.min(y)), (max_x.max(x), max_y.max(y)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_10::generator::generate_serpentine_map;
+    use crate::utils::input_output::read_file;
+
+    fn count_kinds(components: &[PipeComponent]) -> (usize, usize, usize) {
+        components.iter().fold((0, 0, 0), |(loops, chains, isolated), component| match component.kind {
+            ComponentKind::ClosedLoop { .. } => (loops + 1, chains, isolated),
+            ComponentKind::DeadEndChain => (loops, chains + 1, isolated),
+            ComponentKind::IsolatedPipe => (loops, chains, isolated + 1),
+        })
+    }
+
+    #[test]
+    fn test_components_of_example_with_junk() {
+        let content = read_file("resources/input_day_10_test_b.txt").unwrap();
+        let explorer = Explorer::new(&content).unwrap();
+        let components = explorer.pipe_components();
+
+        let main_loop = components.iter().find(|component| component.contains(explorer.start_position())).unwrap();
+        assert_eq!(main_loop.kind, ComponentKind::ClosedLoop { enclosed_tiles: 1 });
+        assert_eq!(main_loop.len(), 8);
+        assert_eq!(main_loop.bounding_box, ((1, 1), (3, 3)));
+
+        let pipe_count = explorer.grid().positions().filter(|&position| explorer.grid().connections(position) != 0).count();
+        assert_eq!(components.iter().map(PipeComponent::len).sum::<usize>(), pipe_count);
+        assert_eq!(count_kinds(&components), (1, 3, 8));
+        assert!(components.contains(&PipeComponent {
+            kind: ComponentKind::DeadEndChain,
+            positions: vec![(4, 3), (4, 2), (4, 1), (4, 0), (3, 0)],
+            bounding_box: ((3, 0), (4, 3)),
+        }));
+    }
+
+    #[test]
+    fn test_separate_loops_chains_and_isolated_pipes() {
+        let explorer = Explorer::new("\
+S-7.F---7
+|.|.|...|
+L-J.L---J
+.F-.|.-..
+.....F7..
+.....LJ..").unwrap();
+        let components = explorer.pipe_components();
+
+        assert_eq!(count_kinds(&components), (3, 1, 2));
+        assert_eq!(components.iter().find(|component| component.kind == ComponentKind::DeadEndChain).unwrap().positions, vec![(2, 3), (1, 3)]);
+
+        let largest = explorer.largest_enclosing_loop().unwrap();
+        assert_eq!(largest.kind, ComponentKind::ClosedLoop { enclosed_tiles: 3 });
+        assert_eq!(largest.bounding_box, ((4, 0), (8, 2)));
+    }
+
+    #[test]
+    fn test_generated_map_is_a_single_loop() {
+        let explorer = Explorer::new(&generate_serpentine_map(7, 6)).unwrap();
+        let components = explorer.pipe_components();
+
+        assert_eq!(components.len(), 1);
+        assert_eq!(components[0].len(), explorer.loop_walk().count());
+        assert_eq!(components[0].kind, ComponentKind::ClosedLoop { enclosed_tiles: explorer.count_enclosed_tiles() });
+    }
+}
@@ -0,0 +1,119 @@
+use std::fmt::Write;
+use std::fs;
+use anyhow::{Context, Result};
+use crate::day_10::explore::Explorer;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PathExportFormat {
+    Csv,
+    Wkt,
+}
+
+impl Explorer {
+    pub fn loop_distances(&self) -> Vec<Option<usize>> {
+        let grid = self.grid();
+        let loop_path = self.loop_positions();
+        let mut distances = vec![None; grid.len()];
+
+        for (step, &position) in loop_path.iter().enumerate() {
+            distances[grid.index(position)] = Some(step.min(loop_path.len() - step));
+        }
+
+        distances
+    }
+
+    pub fn furthest_positions(&self) -> (usize, Vec<(usize, usize)>) {
+        let loop_path = self.loop_positions();
+        let furthest_distance = loop_path.len() / 2;
+        let furthest_positions = loop_path.iter()
+            .enumerate()
+            .filter(|&(step, _)| step.min(loop_path.len() - step) == furthest_distance)
+            .map(|(_, &position)| position)
+            .collect();
+
+        (furthest_distance, furthest_positions)
+    }
+
+    pub fn export_loop_path(&self, format: PathExportFormat) -> String {
+        let loop_path = self.loop_positions();
+        let mut export = String::new();
+
+        match format {
+            PathExportFormat::Csv => {
+                export.push_str("step,x,y,distance\n");
+                for (step, (x, y)) in loop_path.iter().enumerate() {
+                    writeln!(export, "{},{},{},{}", step, x, y, step.min(loop_path.len() - step)).unwrap();
+                }
+            }
+            PathExportFormat::Wkt => {
+                let points: Vec<String> = loop_path.iter()
+                    .chain(loop_path.first())
+                    .map(|(x, y)| format!("{} {}", x, y))
+                    .collect();
+                writeln!(export, "POLYGON(({}))", points.join(", ")).unwrap();
+            }
+        }
+
+        export
+    }
+
+    pub fn write_loop_path(&self, file_path: &str, format: PathExportFormat) -> Result<()> {
+        fs::write(file_path, self.export_loop_path(format)).context("Failed to write loop path")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use super::*;
+    use crate::utils::input_output::read_file;
+
+    #[test]
+    fn test_loop_distances() {
+        let content = read_file("resources/input_day_10_test_c.txt").unwrap();
+        let explorer = Explorer::new(&content).unwrap();
+        let distances: Vec<String> = explorer.loop_distances()
+            .chunks(explorer.grid().width())
+            .map(|row| row.iter().map(|distance| distance.map_or('.', |distance| char::from_digit(distance as u32, 10).unwrap())).collect())
+            .collect();
+
+        assert_eq!(distances, vec!["..45.", ".236.", "01.78", "14567", "23..."]);
+    }
+
+    #[rstest]
+    #[case("resources/input_day_10_test_a.txt", 4, vec![(3, 3)])]
+    #[case("resources/input_day_10_test_c.txt", 8, vec![(4, 2)])]
+    fn test_furthest_positions(#[case] input_file: &str, #[case] expected_distance: usize, #[case] expected_positions: Vec<(usize, usize)>) {
+        let content = read_file(input_file).unwrap();
+        let explorer = Explorer::new(&content).unwrap();
+
+        assert_eq!(explorer.furthest_positions(), (expected_distance, expected_positions));
+        assert_eq!(explorer.find_furthest_distance(), expected_distance);
+    }
+
+    #[test]
+    fn test_export_loop_path() {
+        let content = read_file("resources/input_day_10_test_a.txt").unwrap();
+        let explorer = Explorer::new(&content).unwrap();
+
+        let csv = explorer.export_loop_path(PathExportFormat::Csv);
+        assert_eq!(csv.lines().count(), 9);
+        assert_eq!(csv.lines().take(3).collect::<Vec<_>>(), vec!["step,x,y,distance", "0,1,1,0", "1,1,2,1"]);
+        assert_eq!(csv.lines().last(), Some("7,2,1,1"));
+
+        assert_eq!(explorer.export_loop_path(PathExportFormat::Wkt), "POLYGON((1 1, 1 2, 1 3, 2 3, 3 3, 3 2, 3 1, 2 1, 1 1))\n");
+    }
+
+    #[test]
+    fn test_write_loop_path() {
+        let content = read_file("resources/input_day_10_test_a.txt").unwrap();
+        let explorer = Explorer::new(&content).unwrap();
+        let file_path = std::env::temp_dir().join(format!("aoc_2023_day_10_loop_path_{}.csv", std::process::id()));
+
+        explorer.write_loop_path(file_path.to_str().unwrap(), PathExportFormat::Csv).unwrap();
+        let export = fs::read_to_string(&file_path).unwrap();
+        fs::remove_file(&file_path).unwrap();
+
+        assert_eq!(export, explorer.export_loop_path(PathExportFormat::Csv));
+    }
+}
@@ -0,0 +1,146 @@
+use anyhow::Result;
+use crate::day_10::errors::PipeLoopError;
+use crate::day_10::explore::Explorer;
+use crate::day_10::pipe::NORTH;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EnclosedCountMethod {
+    FloodFill,
+    Shoelace,
+    Scanline,
+    Upscaled,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TileRegion {
+    Loop,
+    Inside,
+    Outside,
+}
+
+impl Explorer {
+    pub fn count_enclosed_tiles(&self) -> usize {
+        self.count_enclosed_tiles_with(EnclosedCountMethod::Shoelace)
+    }
+
+    pub fn count_enclosed_tiles_with(&self, method: EnclosedCountMethod) -> usize {
+        match method {
+            EnclosedCountMethod::FloodFill => self.count_enclosed_tiles_by_flood_fill(),
+            EnclosedCountMethod::Shoelace => self.count_enclosed_tiles_by_shoelace(),
+            EnclosedCountMethod::Scanline => self.count_enclosed_tiles_by_scanline(),
+            EnclosedCountMethod::Upscaled => self.count_enclosed_tiles_by_upscaling(),
+        }
+    }
+
+    pub fn count_enclosed_tiles_cross_checked(&self, methods: &[EnclosedCountMethod]) -> Result<usize> {
+        let counts: Vec<(EnclosedCountMethod, usize)> = methods.iter()
+            .map(|&method| (method, self.count_enclosed_tiles_with(method)))
+            .collect();
+
+        match counts.first() {
+            Some(&(_, count)) if counts.iter().all(|&(_, other_count)| other_count == count) => Ok(count),
+            Some(_) => Err(PipeLoopError::EnclosedCountDisagreement(counts).into()),
+            None => Ok(self.count_enclosed_tiles()),
+        }
+    }
+
+    pub fn count_enclosed_tiles_by_shoelace(&self) -> usize {
+        let (twice_area, loop_length) = self.loop_area_and_length();
+        enclosed_tiles_by_pick(twice_area, loop_length)
+    }
+
+    pub fn count_enclosed_tiles_by_scanline(&self) -> usize {
+        self.tile_regions().into_iter()
+            .filter(|&region| region == TileRegion::Inside)
+            .count()
+    }
+
+    pub fn tile_regions(&self) -> Vec<TileRegion> {
+        let grid = self.grid();
+        let mut regions = vec![TileRegion::Outside; grid.len()];
+        for position in self.loop_walk() {
+            regions[grid.index(position)] = TileRegion::Loop;
+        }
+
+        for (y, row) in grid.rows().enumerate() {
+            let mut is_inside = false;
+            for (x, pipe) in row.iter().enumerate() {
+                let index = grid.index((x, y));
+                if regions[index] == TileRegion::Loop {
+                    if pipe.connections() & NORTH != 0 {
+                        is_inside = !is_inside;
+                    }
+                } else if is_inside {
+                    regions[index] = TileRegion::Inside;
+                }
+            }
+        }
+
+        regions
+    }
+
+    pub fn loop_area_and_length(&self) -> (i64, usize) {
+        polygon_area_and_length(self.loop_walk())
+    }
+}
+
+pub fn polygon_area_and_length(positions: impl IntoIterator<Item = (usize, usize)>) -> (i64, usize) {
+    let mut positions = positions.into_iter();
+    let Some(first_position) = positions.next() else {
+        return (0, 0);
+    };
+
+    let (twice_area, last_position, length) = positions.fold((0, first_position, 1), |(twice_area, previous_position, length), position| {
+        (twice_area + cross_product(previous_position, position), position, length + 1)
+    });
+
+    (twice_area + cross_product(last_position, first_position), length)
+}
+
+pub fn enclosed_tiles_by_pick(twice_area: i64, loop_length: usize) -> usize {
+    ((twice_area.abs() - loop_length as i64) / 2 + 1) as usize
+}
+
+fn cross_product((x1, y1): (usize, usize), (x2, y2): (usize, usize)) -> i64 {
+    x1 as i64 * y2 as i64 - x2 as i64 * y1 as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use super::*;
+    use crate::utils::input_output::read_file;
+
+    const ALL_METHODS: [EnclosedCountMethod; 4] = [EnclosedCountMethod::Shoelace, EnclosedCountMethod::Scanline, EnclosedCountMethod::FloodFill, EnclosedCountMethod::Upscaled];
+
+    #[rstest]
+    #[case("resources/input_day_10_test_a.txt", 1)]
+    #[case("resources/input_day_10_test_b.txt", 1)]
+    #[case("resources/input_day_10_test_c.txt", 1)]
+    #[case("resources/input_day_10_test_d.txt", 1)]
+    #[case("resources/input_day_10_test_e.txt", 4)]
+    #[case("resources/input_day_10_test_f.txt", 8)]
+    #[case("resources/input_day_10_test_g.txt", 10)]
+    fn test_count_enclosed_tiles_cross_checked(#[case] input_file: &str, #[case] expected_count: usize) {
+        let content = read_file(input_file).unwrap();
+        let explorer = Explorer::new(&content).unwrap();
+
+        assert_eq!(explorer.count_enclosed_tiles_cross_checked(&ALL_METHODS).unwrap(), expected_count);
+    }
+
+    #[test]
+    fn test_cross_check_against_flood_fill() {
+        let content = read_file("resources/input_day_10.txt").unwrap();
+        let explorer = Explorer::new(&content).unwrap();
+        assert_eq!(explorer.count_enclosed_tiles_cross_checked(&ALL_METHODS).unwrap(), 595);
+    }
+
+    #[test]
+    fn test_start_on_vertical_pipe_is_counted_by_scanline() {
+        let explorer = Explorer::new("F-7\nS.|\nL-J").unwrap();
+
+        assert_eq!(explorer.count_enclosed_tiles_with(EnclosedCountMethod::Scanline), 1);
+        assert_eq!(explorer.count_enclosed_tiles_with(EnclosedCountMethod::Shoelace), 1);
+        assert_eq!(explorer.count_enclosed_tiles_with(EnclosedCountMethod::Upscaled), 1);
+    }
+}
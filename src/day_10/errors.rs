@@ -0,0 +1,45 @@
+use crate::day_10::enclosed_area::EnclosedCountMethod;
+
+#[derive(Debug)]
+pub enum Day10ParsingError {
+    EmptyMap,
+    RaggedRow { row: usize, expected_length: usize, actual_length: usize },
+    UnknownTile { position: (usize, usize), tile: char },
+    UnknownPipe(char),
+    MissingStart,
+    MultipleStarts(Vec<(usize, usize)>),
+}
+
+impl std::fmt::Display for Day10ParsingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Day10ParsingError::EmptyMap => write!(f, "Pipe map is empty"),
+            Day10ParsingError::RaggedRow { row, expected_length, actual_length } => write!(f, "Row {} has {} tiles, expected {}", row, actual_length, expected_length),
+            Day10ParsingError::UnknownTile { position, tile } => write!(f, "Unknown tile '{}' at {:?}", tile, position),
+            Day10ParsingError::UnknownPipe(tile) => write!(f, "Unknown pipe '{}'", tile),
+            Day10ParsingError::MissingStart => write!(f, "Pipe map has no starting tile 'S'"),
+            Day10ParsingError::MultipleStarts(positions) => write!(f, "Pipe map has multiple starting tiles at {:?}", positions),
+        }
+    }
+}
+
+impl std::error::Error for Day10ParsingError {}
+
+#[derive(Debug)]
+pub enum PipeLoopError {
+    EnclosedCountDisagreement(Vec<(EnclosedCountMethod, usize)>),
+    AmbiguousStart { position: (usize, usize), connections: Vec<(usize, usize)> },
+    BrokenLoop { position: (usize, usize) },
+}
+
+impl std::fmt::Display for PipeLoopError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            PipeLoopError::AmbiguousStart { position, connections } => write!(f, "Starting tile at {:?} has {} connecting neighbours {:?}, expected exactly 2", position, connections.len(), connections),
+            PipeLoopError::EnclosedCountDisagreement(counts) => write!(f, "Enclosed tile counts disagree: {:?}", counts),
+            PipeLoopError::BrokenLoop { position } => write!(f, "Pipe loop is broken at {:?} and does not return to the starting tile", position),
+        }
+    }
+}
+
+impl std::error::Error for PipeLoopError {}
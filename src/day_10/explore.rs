@@ -1,42 +1,139 @@
 use std::collections::HashMap;
 use std::collections::{HashSet, VecDeque};
+use std::fmt;
+use anyhow::Result;
+use crate::day_10::errors::PipeLoopError;
 use crate::day_10::parser::Parser;
+use crate::day_10::pipe::{opposite, Pipe, DIRECTIONS};
+use crate::day_10::pipe_grid::PipeGrid;
 use crate::day_10::tile::Tile;
 use crate::day_10::loop_iterator::LoopIterator;
-use crate::utils::collections::collection_operations::{choose_tuple};
+
+#[allow(dead_code)]
+pub struct TileClassification {
+    pub inside: HashSet<(usize, usize)>,
+    pub outside: HashSet<(usize, usize)>,
+    pub tile_loop: HashSet<(usize, usize)>,
+}
+
+impl TileClassification {
+    #[allow(dead_code)]
+    pub fn area(&self) -> usize {
+        self.inside.len() + self.outside.len() + self.tile_loop.len()
+    }
+}
 
 pub struct Explorer {
-    map: Vec<Vec<char>>,
+    grid: PipeGrid,
     start_position: (usize, usize),
+    start_direction_exclusion: (usize, usize),
 }
 
 impl Explorer {
-    pub fn new(input: &str) -> Self {
-        let parsed_map = Parser::new(input);
-        let start_position = Parser::find_starting_position(&parsed_map).unwrap();
-
-        Explorer {
-            map: parsed_map.map,
+    pub fn new(input: &str) -> Result<Self> {
+        let parsed_map = Parser::new(input)?;
+        let start_position = parsed_map.find_starting_position()?;
+        let mut grid = parsed_map.grid;
+
+        let start_connections: Vec<u8> = DIRECTIONS.into_iter()
+            .filter(|&direction| {
+                grid.neighbour(start_position, direction)
+                    .is_some_and(|neighbour| grid.connections(neighbour) & opposite(direction) != 0)
+            })
+            .collect();
+        let mut start_tile_connections: Vec<(usize, usize)> = start_connections.iter()
+            .filter_map(|&direction| grid.neighbour(start_position, direction))
+            .collect();
+        start_tile_connections.sort();
+
+        let start_direction_exclusion = match start_tile_connections.as_slice() {
+            [_, second_connection] => *second_connection,
+            _ => return Err(PipeLoopError::AmbiguousStart { position: start_position, connections: start_tile_connections }.into()),
+        };
+        let start_pipe = Pipe::from_connections(start_connections.iter().fold(0, |mask, direction| mask | direction))
+            .ok_or(PipeLoopError::AmbiguousStart { position: start_position, connections: start_tile_connections })?;
+        grid.set(start_position, start_pipe);
+
+        let explorer = Explorer {
+            grid,
             start_position,
+            start_direction_exclusion,
+        };
+        explorer.validate_loop()?;
+        Ok(explorer)
+    }
+
+    #[allow(dead_code)]
+    pub fn start_pipe(&self) -> char {
+        self.grid.get(self.start_position).to_char()
+    }
+
+    pub fn start_position(&self) -> (usize, usize) {
+        self.start_position
+    }
+
+    pub fn grid(&self) -> &PipeGrid {
+        &self.grid
+    }
+
+    fn validate_loop(&self) -> Result<(), PipeLoopError> {
+        let mut previous_position = self.start_position;
+        let mut current_position = self.start_direction_exclusion;
+
+        for _ in 0..self.grid.len() {
+            if current_position == self.start_position {
+                return Ok(());
+            }
+
+            match self.next_loop_position(previous_position, current_position) {
+                Some(next_position) => {
+                    previous_position = current_position;
+                    current_position = next_position;
+                }
+                None => return Err(PipeLoopError::BrokenLoop { position: current_position }),
+            }
         }
+
+        Err(PipeLoopError::BrokenLoop { position: current_position })
+    }
+
+    fn next_loop_position(&self, previous_position: (usize, usize), current_position: (usize, usize)) -> Option<(usize, usize)> {
+        let connections = self.grid.connections(current_position);
+        let mut neighbours = DIRECTIONS.into_iter()
+            .filter(|&direction| connections & direction != 0)
+            .map(|direction| self.grid.neighbour(current_position, direction));
+
+        match (neighbours.next()??, neighbours.next()??) {
+            (first, second) if first == previous_position => Some(second),
+            (first, second) if second == previous_position => Some(first),
+            _ => None,
+        }
+    }
+
+    pub fn loop_walk(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        std::iter::successors(Some((self.start_direction_exclusion, self.start_position)), move |&(previous_position, current_position)| {
+            self.next_loop_position(previous_position, current_position)
+                .filter(|&next_position| next_position != self.start_position)
+                .map(|next_position| (current_position, next_position))
+        })
+        .map(|(_, position)| position)
     }
 
     pub fn find_furthest_distance(&self) -> usize {
-        let map_data = self.get_map_tile_data();
-        let start_tile = map_data.get(&self.start_position);
-        let start_tile_connections = start_tile.unwrap().connections();
-        let start_direction_exclusion = choose_tuple(start_tile_connections);
-        let tile_loop_iterator = LoopIterator::new(&map_data, self.start_position, start_direction_exclusion);
-        let total_jumps = tile_loop_iterator.map(|(count, _, _)| count).sum::<usize>();
-        total_jumps / 2
+        self.loop_walk().count() / 2
+    }
+
+    pub fn loop_positions(&self) -> Vec<(usize, usize)> {
+        self.loop_walk().collect()
     }
 
-    pub fn count_enclosed_tiles(&self) -> usize {
+    pub fn count_enclosed_tiles_by_flood_fill(&self) -> usize {
+        self.classify_tiles().inside.len()
+    }
+
+    pub fn classify_tiles(&self) -> TileClassification {
         let map_data = self.get_map_tile_data();
-        let start_tile = map_data.get(&self.start_position);
-        let start_tile_connections = start_tile.unwrap().connections();
-        let start_direction_exclusion = choose_tuple(start_tile_connections);
-        let tile_loop_iterator = LoopIterator::new(&map_data, self.start_position,start_direction_exclusion);
+        let tile_loop_iterator = LoopIterator::new(&map_data, self.start_position, self.start_direction_exclusion);
 
         let mut tile_loop = HashSet::new();
         let mut flood_seed_candidates = HashSet::new();
@@ -49,8 +146,18 @@ impl Explorer {
         }
 
         let flood_seeds: HashSet<(usize, usize)> = flood_seed_candidates.difference(&tile_loop).cloned().collect();
-        let contained_tiles = self.flood_fill(&tile_loop, &flood_seeds).len();
-        contained_tiles
+        let right_side = self.flood_fill(&tile_loop, &flood_seeds);
+        let other_side: HashSet<(usize, usize)> = self.grid.positions()
+            .filter(|position| !tile_loop.contains(position) && !right_side.contains(position))
+            .collect();
+
+        let (inside, outside) = if self.loop_area_and_length().0 > 0 {
+            (right_side, other_side)
+        } else {
+            (other_side, right_side)
+        };
+
+        TileClassification { inside, outside, tile_loop }
     }
 
     pub fn flood_fill(&self, tile_loop: &HashSet<(usize, usize)>, flood_seeds: &HashSet<(usize, usize)>) -> HashSet<(usize, usize)> {
@@ -63,21 +170,18 @@ impl Explorer {
             }
         }
 
-        let directions = [(0, 1), (0, -1), (1, 0), (-1, 0)];
-
-        while let Some((x, y)) = to_visit.pop_front() {
-            if visited.contains(&(x, y)) {
+        while let Some(position) = to_visit.pop_front() {
+            if !self.is_on_map(position.0 as isize, position.1 as isize) || visited.contains(&position) {
                 continue;
             }
 
-            visited.insert((x, y));
+            visited.insert(position);
 
-            for &(dx, dy) in &directions {
-                let nx = (x as isize + dx) as usize;
-                let ny = (y as isize + dy) as usize;
-
-                if !tile_loop.contains(&(nx, ny)) {
-                    to_visit.push_back((nx, ny));
+            for direction in DIRECTIONS {
+                if let Some(neighbour) = self.grid.neighbour(position, direction) {
+                    if !tile_loop.contains(&neighbour) {
+                        to_visit.push_back(neighbour);
+                    }
                 }
             }
         }
@@ -86,27 +190,13 @@ impl Explorer {
     }
 
     pub fn get_map_tile_data(&self) -> HashMap<(usize, usize), Tile> {
-        let mut tile_data_map = HashMap::new();
-
-        for (x, y, tile_char) in self.iterate_map_tiles() {
-            let tile = self.process_tile_data(x, y, tile_char);
-            tile_data_map.insert((x, y), tile);
-        }
-
-        let start_tile_connections = self.get_start_tile_connections(&tile_data_map);
-        tile_data_map.insert(self.start_position, Tile::new('S',start_tile_connections));
-
-        tile_data_map
-    }
-
-    fn iterate_map_tiles<'a>(&'a self) -> impl Iterator<Item = (usize, usize, char)> + 'a {
-        self.map.iter().enumerate().flat_map(move |(y, row)| {
-            row.iter().enumerate().map(move |(x, &tile_char)| (x, y, tile_char))
-        })
+        self.grid.positions()
+            .map(|(x, y)| ((x, y), self.process_tile_data(x, y, self.grid.get((x, y)))))
+            .collect()
     }
 
-    pub fn process_tile_data(&self, x: usize, y: usize, tile_char: char) -> Tile {
-        let mut tile = Tile::new(tile_char, vec![],);
+    pub fn process_tile_data(&self, x: usize, y: usize, pipe: Pipe) -> Tile {
+        let mut tile = Tile::new(pipe, vec![],);
         let receptors = tile.receptors();
 
         let mut updated_connections = Vec::new();
@@ -124,14 +214,16 @@ impl Explorer {
     }
 
     fn is_on_map(&self, x: isize, y: isize) -> bool {
-        x >= 0 && (x as usize) < self.map[0].len() && y >= 0 && (y as usize) < self.map.len()
+        x >= 0 && (x as usize) < self.grid.width() && y >= 0 && (y as usize) < self.grid.height()
     }
+}
 
-    pub fn get_start_tile_connections(&self, map_tile_data: &HashMap<(usize, usize), Tile>) -> Vec<(usize, usize)> {
-        map_tile_data.iter()
-            .filter(|&(_, tile)| tile.connections().contains(&self.start_position))
-            .map(|(&pos, _)| pos)
-            .collect()
+impl fmt::Display for Explorer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.grid.rows() {
+            writeln!(f, "{}", row.iter().map(|pipe| pipe.to_char()).collect::<String>())?;
+        }
+        Ok(())
     }
 }
 
@@ -144,7 +236,7 @@ mod tests {
     #[test]
     fn test_is_on_map() {
         let content = read_file("resources/input_day_10_test_a.txt").unwrap();
-        let explorer = Explorer::new(&content);
+        let explorer = Explorer::new(&content).unwrap();
         assert!(explorer.is_on_map(3,2))
     }
 
@@ -157,7 +249,7 @@ mod tests {
     pub fn test_get_map_tile_data(#[case] input_file: &str, #[case] expected: usize) {
 
         let content = read_file(input_file).unwrap();
-        let explorer = Explorer::new(&content);
+        let explorer = Explorer::new(&content).unwrap();
         let map =explorer.get_map_tile_data();
         let tiles_with_receptacle = remove_tiles_without_receptacle(map);
 
@@ -166,7 +258,7 @@ mod tests {
 
     pub fn remove_tiles_without_receptacle(map_tile_data: HashMap<(usize, usize), Tile>) -> HashMap<(usize, usize), Tile> {
         map_tile_data.into_iter()
-            .filter(|&(_, ref tile)| !tile.connections().is_empty())
+            .filter(|(_, tile)| !tile.connections().is_empty())
             .collect()
     }
 
@@ -178,7 +270,7 @@ mod tests {
 
     fn test_find_furthest_distance(#[case] input_file:&str, #[case] furthest_distance:usize) {
         let content = read_file(input_file).unwrap();
-        let explorer = Explorer::new(&content);
+        let explorer = Explorer::new(&content).unwrap();
         let furthest_position = explorer.find_furthest_distance();
 
         assert_eq!(furthest_position,furthest_distance);
@@ -188,25 +280,25 @@ mod tests {
     #[case("resources/input_day_10_test_a.txt")]
     fn test_visualize_lateral_data(#[case] input_file:&str){
         let content = read_file(input_file).unwrap();
-        let explorer = Explorer::new(&content);
+        let explorer = Explorer::new(&content).unwrap();
         explorer.count_enclosed_tiles();
     }
 
     #[test]
     fn test_visualize_lateral_data1(){
         let content = read_file("resources/input_day_10_test_c.txt").unwrap();
-        let explorer = Explorer::new(&content);
+        let explorer = Explorer::new(&content).unwrap();
         explorer.count_enclosed_tiles();
     }
 
     #[rstest]
     #[case("resources/input_day_10_test_a.txt",1)]
-    //#[case("resources/input_day_10_test_e.txt",4)]
-    //#[case("resources/input_day_10_test_f.txt",8)]
-    //#[case("resources/input_day_10_test_g.txt",10)]
+    #[case("resources/input_day_10_test_e.txt",4)]
+    #[case("resources/input_day_10_test_f.txt",8)]
+    #[case("resources/input_day_10_test_g.txt",10)]
     fn test_count_enclosed_tiles(#[case] input_file:&str, #[case] expected_count: usize){
         let content = read_file(input_file).unwrap();
-        let explorer = Explorer::new(&content);
+        let explorer = Explorer::new(&content).unwrap();
         let enclosed_tile_count = explorer.count_enclosed_tiles();
 
         assert_eq!(enclosed_tile_count, expected_count);
@@ -215,10 +307,102 @@ mod tests {
     #[test]
     fn test_count_enclosed_tiles1(){
         let content = read_file("resources/input_day_10.txt").unwrap();
-        let explorer = Explorer::new(&content);
+        let explorer = Explorer::new(&content).unwrap();
         let enclosed_tile_count = explorer.count_enclosed_tiles();
 
-        assert_eq!(enclosed_tile_count, 8);
+        assert_eq!(enclosed_tile_count, 595);
+    }
+
+    #[rstest]
+    #[case("resources/input_day_10_test_a.txt", 'F')]
+    #[case("resources/input_day_10_test_b.txt", 'F')]
+    #[case("resources/input_day_10_test_c.txt", 'F')]
+    #[case("resources/input_day_10_test_e.txt", 'F')]
+    #[case("resources/input_day_10_test_f.txt", 'F')]
+    #[case("resources/input_day_10_test_g.txt", '7')]
+    fn test_start_pipe(#[case] input_file: &str, #[case] expected_pipe: char) {
+        let content = read_file(input_file).unwrap();
+        let explorer = Explorer::new(&content).unwrap();
+
+        assert_eq!(explorer.start_pipe(), expected_pipe);
+        assert_eq!(explorer.get_map_tile_data()[&explorer.start_position].receptors(), Tile::new(Pipe::try_from(expected_pipe).unwrap(), vec![]).receptors());
+    }
+
+    #[test]
+    fn test_display_renders_start_pipe() {
+        let content = read_file("resources/input_day_10_test_a.txt").unwrap();
+        let explorer = Explorer::new(&content).unwrap();
+
+        assert_eq!(explorer.to_string(), ".....\n.F-7.\n.|.|.\n.L-J.\n.....\n");
+    }
+
+    #[rstest]
+    #[case("resources/input_day_10_test_a.txt", 1, 16, 8)]
+    #[case("resources/input_day_10_test_c.txt", 1, 8, 16)]
+    #[case("resources/input_day_10_test_e.txt", 4, 49, 46)]
+    #[case("resources/input_day_10_test_f.txt", 8, 52, 140)]
+    #[case("resources/input_day_10_test_g.txt", 10, 30, 160)]
+    fn test_classify_tiles(#[case] input_file: &str, #[case] expected_inside: usize, #[case] expected_outside: usize, #[case] expected_loop: usize) {
+        let content = read_file(input_file).unwrap();
+        let explorer = Explorer::new(&content).unwrap();
+        let classification = explorer.classify_tiles();
+
+        assert_eq!(classification.inside.len(), expected_inside);
+        assert_eq!(classification.outside.len(), expected_outside);
+        assert_eq!(classification.tile_loop.len(), expected_loop);
+        assert_eq!(classification.area(), explorer.grid.len());
+        assert!(classification.inside.is_disjoint(&classification.outside));
+    }
+
+    #[test]
+    fn test_flood_fill_stays_on_map() {
+        let content = read_file("resources/input_day_10_test_a.txt").unwrap();
+        let explorer = Explorer::new(&content).unwrap();
+        let tile_loop: HashSet<(usize, usize)> = explorer.loop_positions().into_iter().collect();
+        let filled = explorer.flood_fill(&tile_loop, &HashSet::from([(0, 0), (7, 7)]));
+
+        assert_eq!(filled.len(), 16);
+        assert!(filled.iter().all(|&(x, y)| x < 5 && y < 5));
+    }
+
+    #[rstest]
+    #[case("...\n.S.\n...", (1, 1), 0)]
+    #[case(".|.\n-S-\n...", (1, 1), 3)]
+    #[case("S-.\n...", (0, 0), 1)]
+    fn test_ambiguous_start_is_rejected(#[case] input: &str, #[case] expected_position: (usize, usize), #[case] expected_connections: usize) {
+        match Explorer::new(input) {
+            Err(error) => match error.downcast_ref::<PipeLoopError>() {
+                Some(PipeLoopError::AmbiguousStart { position, connections }) => {
+                    assert_eq!(*position, expected_position);
+                    assert_eq!(connections.len(), expected_connections);
+                }
+                _ => panic!("Expected ambiguous start, got {}", error),
+            },
+            Ok(_) => panic!("Expected ambiguous start"),
+        }
+    }
+
+    #[rstest]
+    #[case("S-7\n|.|\nL-.", (2, 2))]
+    #[case("S-\n|.", (1, 0))]
+    #[case("S-7\n|.|\nL-|", (2, 2))]
+    fn test_broken_loop_is_rejected(#[case] input: &str, #[case] expected_position: (usize, usize)) {
+        match Explorer::new(input) {
+            Err(error) => match error.downcast_ref::<PipeLoopError>() {
+                Some(PipeLoopError::BrokenLoop { position }) => assert_eq!(*position, expected_position),
+                _ => panic!("Expected broken loop, got {}", error),
+            },
+            Ok(_) => panic!("Expected broken loop"),
+        }
+    }
+
+    #[test]
+    fn test_parsing_errors_are_reported() {
+        let error = Explorer::new("S-7\n|.|\nL-JS").err().unwrap();
+        assert_eq!(error.to_string(), "Row 2 has 4 tiles, expected 3");
+
+        let error = Explorer::new("F-7\n|.|\nL-J").err().unwrap();
+        assert_eq!(error.to_string(), "Pipe map has no starting tile 'S'");
     }
 }
 
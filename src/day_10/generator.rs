@@ -0,0 +1,93 @@
+use crate::day_10::pipe::{opposite, Pipe, EAST, NORTH, SOUTH, WEST};
+
+pub fn generate_serpentine_map(snake_columns: usize, height: usize) -> String {
+    let snake_columns = snake_columns | 1;
+    let height = height.max(3);
+    let width = 2 * snake_columns;
+
+    let mut corners = vec![(0, 0), (1, 0)];
+    for column in 0..snake_columns {
+        let x = 1 + 2 * column;
+        let (top, bottom) = if column % 2 == 0 { (0, height - 2) } else { (height - 2, 0) };
+        corners.push((x, top));
+        corners.push((x, bottom));
+        if column + 1 < snake_columns {
+            corners.push((x + 2, bottom));
+        }
+    }
+    corners.extend([(width - 1, height - 1), (0, height - 1), (0, 0)]);
+
+    let mut connections = vec![0u8; width * height];
+    for segment in corners.windows(2) {
+        draw_segment(&mut connections, width, segment[0], segment[1]);
+    }
+
+    let mut map = String::with_capacity((width + 1) * height);
+    for (index, &connection) in connections.iter().enumerate() {
+        let tile = if index == 0 {
+            'S'
+        } else {
+            Pipe::from_connections(connection).unwrap_or(Pipe::Ground).to_char()
+        };
+        map.push(tile);
+        if (index + 1) % width == 0 {
+            map.push('\n');
+        }
+    }
+
+    map
+}
+
+fn draw_segment(connections: &mut [u8], width: usize, from: (usize, usize), to: (usize, usize)) {
+    let mut position = from;
+    while position != to {
+        let (direction, next_position) = if position.0 < to.0 {
+            (EAST, (position.0 + 1, position.1))
+        } else if position.0 > to.0 {
+            (WEST, (position.0 - 1, position.1))
+        } else if position.1 < to.1 {
+            (SOUTH, (position.0, position.1 + 1))
+        } else {
+            (NORTH, (position.0, position.1 - 1))
+        };
+
+        connections[position.1 * width + position.0] |= direction;
+        connections[next_position.1 * width + next_position.0] |= opposite(direction);
+        position = next_position;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use super::*;
+    use crate::day_10::enclosed_area::EnclosedCountMethod;
+    use crate::day_10::explore::Explorer;
+
+    #[test]
+    fn test_generate_serpentine_map() {
+        let map = generate_serpentine_map(3, 4);
+        assert_eq!(map, "S7.F-7\n||.|.|\n|L-J.|\nL----J\n");
+    }
+
+    #[rstest]
+    #[case(1, 3)]
+    #[case(3, 4)]
+    #[case(5, 7)]
+    #[case(9, 12)]
+    fn test_enclosed_methods_agree_on_generated_maps(#[case] snake_columns: usize, #[case] height: usize) {
+        let explorer = Explorer::new(&generate_serpentine_map(snake_columns, height)).unwrap();
+        let methods = [EnclosedCountMethod::Shoelace, EnclosedCountMethod::Scanline, EnclosedCountMethod::FloodFill, EnclosedCountMethod::Upscaled];
+
+        assert!(explorer.count_enclosed_tiles_cross_checked(&methods).is_ok());
+    }
+
+    #[test]
+    fn test_large_generated_map() {
+        let explorer = Explorer::new(&generate_serpentine_map(1001, 1000)).unwrap();
+        let loop_length = explorer.loop_walk().count();
+
+        assert_eq!(explorer.find_furthest_distance(), loop_length / 2);
+        assert_eq!(explorer.count_enclosed_tiles_by_shoelace(), explorer.count_enclosed_tiles_by_scanline());
+    }
+}
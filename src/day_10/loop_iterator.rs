@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 use crate::day_10::tile::Tile;
-use crate::utils::collections::collection_operations::{add_tuples, subtract_tuples};
+use crate::day_10::pipe::{direction_offset, direction_towards, DIRECTIONS};
+use crate::utils::collections::collection_operations::add_tuples;
 
 pub struct LoopIterator<'a> {
     tile_map: &'a HashMap<(usize, usize), Tile>,
@@ -21,38 +22,15 @@ impl<'a> LoopIterator<'a> {
         }
     }
 
-    fn determine_if_forward(&self, previous_position: (usize, usize), current_position: (usize, usize), tile_data: &Tile) -> bool {
+    fn positions_to_check_right(&self, current_position: (usize, usize), inspected_tile: &Tile, entry: u8) -> Vec<(usize, usize)> {
+        let right_side = inspected_tile.pipe().right_side(entry);
 
-        let entry_point = subtract_tuples (previous_position, current_position);
-
-        if tile_data.receptors().contains(&entry_point){
-            if tile_data.receptors()[0] == entry_point {
-                return true;
-            }
-        }
-        false
-    }
-
-    fn positions_to_check_right(&self, current_position:(usize, usize), inspected_tile: &Tile, is_forward: bool) -> Vec<(usize, usize)> {
-        let mut to_inspect = Vec::new();
-
-        if !(&inspected_tile.lateral_data().is_empty()){
-            if is_forward {
-                for positions_to_check in &inspected_tile.lateral_data()[0]{
-                    to_inspect.push(add_tuples(current_position, *positions_to_check))
-                }
-            } else {
-                for positions_to_check in &inspected_tile.lateral_data()[1]{
-                    to_inspect.push(add_tuples(current_position, *positions_to_check))
-                }
-            }
-        }
-
-        let to_inspect: Vec<(usize, usize)> = to_inspect.iter()
-            .map(|&(x, y)| (x.max(0) as usize, y.max(0) as usize))
-            .collect();
-
-        to_inspect
+        DIRECTIONS.into_iter()
+            .filter(|&direction| right_side & direction != 0)
+            .map(|direction| add_tuples(current_position, direction_offset(direction)))
+            .filter(|&(x, y)| x >= 0 && y >= 0)
+            .map(|(x, y)| (x as usize, y as usize))
+            .collect()
     }
 }
 
@@ -67,11 +45,11 @@ impl<'a> Iterator for LoopIterator<'a> {
         self.has_started = true;
         if let Some(tile) = self.tile_map.get(&self.current_position) {
             if let Some(&next_position) = tile.connections().iter().find(|&&position| position != self.previous_position) {
-                let is_forward = self.determine_if_forward(self.previous_position, self.current_position, tile);
+                let entry = direction_towards(self.current_position, self.previous_position);
 
                 self.previous_position = self.current_position;
                 self.current_position = next_position;
-                let positions_to_check =  self.positions_to_check_right(self.previous_position, &tile, is_forward);
+                let positions_to_check =  self.positions_to_check_right(self.previous_position, tile, entry);
 
                 Some((1, self.previous_position , positions_to_check))
             } else {
@@ -91,7 +69,7 @@ mod tests{
     #[test]
     fn test_visualize_lateral_data1(){
         let content = read_file("resources/input_day_10_test_c.txt").unwrap();
-        let explorer = Explorer::new(&content);
+        let explorer = Explorer::new(&content).unwrap();
         explorer.count_enclosed_tiles();
     }
 }
\ No newline at end of file
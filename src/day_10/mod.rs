@@ -1,16 +1,31 @@
+use crate::day_10::enclosed_area::EnclosedCountMethod;
 use crate::day_10::explore::Explorer;
+use crate::day_10::render::RenderStyle;
 use crate::utils::input_output::read_file;
 use anyhow::{Result};
 
 mod parser;
+mod errors;
 mod explore;
 mod tile;
 mod loop_iterator;
+mod enclosed_area;
+mod pipe;
+mod pipe_grid;
+mod render;
+#[allow(dead_code)]
+mod components;
+#[allow(dead_code)]
+mod distances;
+mod upscale;
+#[allow(dead_code)]
+mod generator;
 
 pub fn solve_day_10() -> Result<(usize,usize)> {
         let content = read_file("resources/input_day_10.txt")?;
-        let explorer = Explorer::new(&content);
+        let explorer = Explorer::new(&content)?;
+        log::debug!("\n{}", explorer.render(RenderStyle::Coloured));
         let furthest_position = explorer.find_furthest_distance();
-        let enclosed_tile_count = explorer.count_enclosed_tiles();
+        let enclosed_tile_count = explorer.count_enclosed_tiles_cross_checked(&[EnclosedCountMethod::Shoelace, EnclosedCountMethod::Scanline, EnclosedCountMethod::FloodFill, EnclosedCountMethod::Upscaled])?;
         Ok((furthest_position, enclosed_tile_count))
 }
\ No newline at end of file
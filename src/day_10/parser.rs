@@ -1,22 +1,52 @@
+use anyhow::Result;
+use crate::day_10::errors::Day10ParsingError;
+use crate::day_10::pipe::Pipe;
+use crate::day_10::pipe_grid::PipeGrid;
+
 pub struct Parser {
-   pub map: Vec<Vec<char>>,
+   pub grid: PipeGrid,
 }
 
 impl Parser {
-    pub fn new(input: &str) -> Self {
-        Parser {
-            map: input.lines()
-                .map(|line| line.chars().collect())
-                .collect(),
+    pub fn new(input: &str) -> Result<Self> {
+        let mut tiles = Vec::with_capacity(input.len());
+        let mut expected_length = None;
+        let mut height = 0;
+
+        for (y, line) in input.lines().enumerate() {
+            let row_start = tiles.len();
+            for (x, tile) in line.chars().enumerate() {
+                let pipe = Pipe::try_from(tile).map_err(|_| Day10ParsingError::UnknownTile { position: (x, y), tile })?;
+                tiles.push(pipe);
+            }
+
+            let actual_length = tiles.len() - row_start;
+            match expected_length {
+                None => expected_length = Some(actual_length),
+                Some(expected_length) if expected_length != actual_length => {
+                    return Err(Day10ParsingError::RaggedRow { row: y, expected_length, actual_length }.into());
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        match expected_length {
+            Some(width) if width > 0 => Ok(Parser { grid: PipeGrid::new(width, height, tiles) }),
+            _ => Err(Day10ParsingError::EmptyMap.into()),
         }
     }
 
-    pub fn find_starting_position(&self) -> Option<(usize, usize)> {
-        self.map.iter().enumerate().find_map(|(y, row)| {
-            row.iter().enumerate().find_map(|(x, &ch)| {
-                if ch == 'S' { Some((x, y)) } else { None }
-            })
-        })
+    pub fn find_starting_position(&self) -> Result<(usize, usize), Day10ParsingError> {
+        let start_positions: Vec<(usize, usize)> = self.grid.positions()
+            .filter(|&position| self.grid.get(position) == Pipe::Start)
+            .collect();
+
+        match start_positions.as_slice() {
+            [] => Err(Day10ParsingError::MissingStart),
+            [start_position] => Ok(*start_position),
+            _ => Err(Day10ParsingError::MultipleStarts(start_positions)),
+        }
     }
 }
 
@@ -47,7 +77,27 @@ mod tests {
 
     fn test_find_starting_position(#[case] input_file: &str, #[case]expected_position: (usize,usize)) {
         let content = read_file(input_file).unwrap();
-        let parser = Parser::new(&content);
+        let parser = Parser::new(&content).unwrap();
         assert_eq!(parser.find_starting_position().unwrap(), expected_position);
     }
+
+    #[rstest]
+    #[case("", "Pipe map is empty")]
+    #[case(".S-7.\n.|.|\n.L-J.", "Row 1 has 4 tiles, expected 5")]
+    #[case(".S-7.\n.|x|.\n.L-J.", "Unknown tile 'x' at (2, 1)")]
+    fn test_invalid_maps_are_rejected(#[case] input: &str, #[case] expected_error: &str) {
+        match Parser::new(input) {
+            Err(error) => assert_eq!(error.to_string(), expected_error),
+            Ok(_) => panic!("Expected invalid map"),
+        }
+    }
+
+    #[test]
+    fn test_start_must_be_unique() {
+        let parser = Parser::new("F-7\n|.|\nL-J").unwrap();
+        assert!(matches!(parser.find_starting_position(), Err(Day10ParsingError::MissingStart)));
+
+        let parser = Parser::new("S-7\n|.|\nL-S").unwrap();
+        assert!(matches!(parser.find_starting_position(), Err(Day10ParsingError::MultipleStarts(positions)) if positions == vec![(0, 0), (2, 2)]));
+    }
 }
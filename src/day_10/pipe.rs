@@ -0,0 +1,186 @@
+use std::fmt;
+use crate::day_10::errors::Day10ParsingError;
+
+pub const NORTH: u8 = 0b0001;
+pub const EAST: u8 = 0b0010;
+pub const SOUTH: u8 = 0b0100;
+pub const WEST: u8 = 0b1000;
+pub const DIRECTIONS: [u8; 4] = [NORTH, EAST, SOUTH, WEST];
+
+pub fn opposite(directions: u8) -> u8 {
+    rotate_clockwise(rotate_clockwise(directions))
+}
+
+pub fn rotate_clockwise(directions: u8) -> u8 {
+    ((directions << 1) | (directions >> 3)) & 0b1111
+}
+
+#[allow(dead_code)]
+pub fn rotate_counter_clockwise(directions: u8) -> u8 {
+    ((directions >> 1) | (directions << 3)) & 0b1111
+}
+
+pub fn direction_offset(direction: u8) -> (isize, isize) {
+    match direction {
+        NORTH => (0, -1),
+        EAST => (1, 0),
+        SOUTH => (0, 1),
+        WEST => (-1, 0),
+        _ => (0, 0),
+    }
+}
+
+pub fn direction_towards((x, y): (usize, usize), (other_x, other_y): (usize, usize)) -> u8 {
+    DIRECTIONS.into_iter()
+        .find(|&direction| {
+            let (dx, dy) = direction_offset(direction);
+            x.checked_add_signed(dx) == Some(other_x) && y.checked_add_signed(dy) == Some(other_y)
+        })
+        .unwrap_or(0)
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pipe {
+    Vertical,
+    Horizontal,
+    NE,
+    NW,
+    SW,
+    SE,
+    Ground,
+    Start,
+}
+
+impl Pipe {
+    pub const CONNECTING: [Pipe; 6] = [Pipe::Vertical, Pipe::Horizontal, Pipe::NE, Pipe::NW, Pipe::SW, Pipe::SE];
+
+    pub fn connections(self) -> u8 {
+        match self {
+            Pipe::Vertical => NORTH | SOUTH,
+            Pipe::Horizontal => EAST | WEST,
+            Pipe::NE => NORTH | EAST,
+            Pipe::NW => NORTH | WEST,
+            Pipe::SW => SOUTH | WEST,
+            Pipe::SE => SOUTH | EAST,
+            Pipe::Ground | Pipe::Start => 0,
+        }
+    }
+
+    pub fn from_connections(connections: u8) -> Option<Pipe> {
+        Pipe::CONNECTING.into_iter().find(|pipe| pipe.connections() == connections)
+    }
+
+    pub fn to_char(self) -> char {
+        match self {
+            Pipe::Vertical => '|',
+            Pipe::Horizontal => '-',
+            Pipe::NE => 'L',
+            Pipe::NW => 'J',
+            Pipe::SW => '7',
+            Pipe::SE => 'F',
+            Pipe::Ground => '.',
+            Pipe::Start => 'S',
+        }
+    }
+
+    pub fn exit(self, entry: u8) -> Option<u8> {
+        let connections = self.connections();
+        if connections & entry == 0 {
+            return None;
+        }
+        Some(connections & !entry)
+    }
+
+    pub fn right_side(self, entry: u8) -> u8 {
+        self.side(entry, rotate_clockwise)
+    }
+
+    #[allow(dead_code)]
+    pub fn left_side(self, entry: u8) -> u8 {
+        self.side(entry, rotate_counter_clockwise)
+    }
+
+    fn side(self, entry: u8, turn: fn(u8) -> u8) -> u8 {
+        match self.exit(entry) {
+            Some(exit) => (turn(opposite(entry)) | turn(exit)) & !self.connections(),
+            None => 0,
+        }
+    }
+}
+
+impl TryFrom<char> for Pipe {
+    type Error = Day10ParsingError;
+
+    fn try_from(tile: char) -> Result<Self, Self::Error> {
+        match tile {
+            '|' => Ok(Pipe::Vertical),
+            '-' => Ok(Pipe::Horizontal),
+            'L' => Ok(Pipe::NE),
+            'J' => Ok(Pipe::NW),
+            '7' => Ok(Pipe::SW),
+            'F' => Ok(Pipe::SE),
+            '.' => Ok(Pipe::Ground),
+            'S' => Ok(Pipe::Start),
+            _ => Err(Day10ParsingError::UnknownPipe(tile)),
+        }
+    }
+}
+
+impl fmt::Display for Pipe {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use super::*;
+
+    #[rstest]
+    #[case('|', NORTH | SOUTH)]
+    #[case('-', EAST | WEST)]
+    #[case('L', NORTH | EAST)]
+    #[case('J', NORTH | WEST)]
+    #[case('7', SOUTH | WEST)]
+    #[case('F', SOUTH | EAST)]
+    #[case('.', 0)]
+    #[case('S', 0)]
+    fn test_try_from_and_display_round_trip(#[case] tile: char, #[case] expected_connections: u8) {
+        let pipe = Pipe::try_from(tile).unwrap();
+
+        assert_eq!(pipe.connections(), expected_connections);
+        assert_eq!(pipe.to_string(), tile.to_string());
+        if expected_connections != 0 {
+            assert_eq!(Pipe::from_connections(expected_connections), Some(pipe));
+        }
+    }
+
+    #[test]
+    fn test_unknown_pipe_is_rejected() {
+        assert!(matches!(Pipe::try_from('x'), Err(Day10ParsingError::UnknownPipe('x'))));
+    }
+
+    #[rstest]
+    #[case(Pipe::Vertical, SOUTH, EAST, WEST)]
+    #[case(Pipe::Vertical, NORTH, WEST, EAST)]
+    #[case(Pipe::Horizontal, WEST, SOUTH, NORTH)]
+    #[case(Pipe::NE, NORTH, SOUTH | WEST, 0)]
+    #[case(Pipe::NE, EAST, 0, SOUTH | WEST)]
+    #[case(Pipe::SE, SOUTH, 0, NORTH | WEST)]
+    #[case(Pipe::Ground, NORTH, 0, 0)]
+    fn test_sides_follow_entry_direction(#[case] pipe: Pipe, #[case] entry: u8, #[case] expected_right: u8, #[case] expected_left: u8) {
+        assert_eq!(pipe.right_side(entry), expected_right);
+        assert_eq!(pipe.left_side(entry), expected_left);
+    }
+
+    #[test]
+    fn test_direction_helpers() {
+        assert_eq!(opposite(NORTH), SOUTH);
+        assert_eq!(opposite(EAST | SOUTH), WEST | NORTH);
+        assert_eq!(direction_towards((1, 1), (1, 0)), NORTH);
+        assert_eq!(direction_towards((0, 0), (1, 0)), EAST);
+        assert_eq!(direction_towards((0, 0), (2, 0)), 0);
+    }
+}
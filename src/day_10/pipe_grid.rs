@@ -0,0 +1,80 @@
+use crate::day_10::pipe::{direction_offset, Pipe};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PipeGrid {
+    width: usize,
+    height: usize,
+    tiles: Vec<Pipe>,
+}
+
+impl PipeGrid {
+    pub fn new(width: usize, height: usize, tiles: Vec<Pipe>) -> Self {
+        PipeGrid { width, height, tiles }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    pub fn index(&self, (x, y): (usize, usize)) -> usize {
+        y * self.width + x
+    }
+
+    pub fn get(&self, position: (usize, usize)) -> Pipe {
+        self.tiles[self.index(position)]
+    }
+
+    pub fn set(&mut self, position: (usize, usize), pipe: Pipe) {
+        let index = self.index(position);
+        self.tiles[index] = pipe;
+    }
+
+    pub fn connections(&self, position: (usize, usize)) -> u8 {
+        self.get(position).connections()
+    }
+
+    pub fn neighbour(&self, (x, y): (usize, usize), direction: u8) -> Option<(usize, usize)> {
+        let (dx, dy) = direction_offset(direction);
+        let nx = x.checked_add_signed(dx)?;
+        let ny = y.checked_add_signed(dy)?;
+
+        if nx < self.width && ny < self.height {
+            Some((nx, ny))
+        } else {
+            None
+        }
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.height).flat_map(move |y| (0..self.width).map(move |x| (x, y)))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[Pipe]> {
+        self.tiles.chunks(self.width.max(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_10::pipe::{EAST, NORTH, SOUTH, WEST};
+
+    #[test]
+    fn test_neighbour_is_bounded() {
+        let grid = PipeGrid::new(2, 2, vec![Pipe::Ground; 4]);
+
+        assert_eq!(grid.neighbour((0, 0), NORTH), None);
+        assert_eq!(grid.neighbour((0, 0), WEST), None);
+        assert_eq!(grid.neighbour((1, 1), EAST), None);
+        assert_eq!(grid.neighbour((0, 0), SOUTH), Some((0, 1)));
+        assert_eq!(grid.neighbour((0, 1), EAST), Some((1, 1)));
+    }
+}
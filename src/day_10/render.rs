@@ -0,0 +1,126 @@
+use std::fmt::Write;
+use std::fs;
+use anyhow::{Context, Result};
+use colored::{ColoredString, Colorize};
+use crate::day_10::enclosed_area::TileRegion;
+use crate::day_10::explore::Explorer;
+use crate::day_10::pipe::Pipe;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RenderStyle {
+    #[allow(dead_code)]
+    Plain,
+    Coloured,
+}
+
+impl Explorer {
+    pub fn render(&self, style: RenderStyle) -> String {
+        let grid = self.grid();
+        let regions = self.tile_regions();
+        let mut picture = String::with_capacity(grid.len() * 4);
+
+        for (y, row) in grid.rows().enumerate() {
+            for (x, &pipe) in row.iter().enumerate() {
+                let position = (x, y);
+                let region = regions[grid.index(position)];
+                match style {
+                    RenderStyle::Plain => picture.push(plain_glyph(pipe, region)),
+                    RenderStyle::Coloured => write!(picture, "{}", self.coloured_glyph(position, pipe, region)).unwrap(),
+                }
+            }
+            picture.push('\n');
+        }
+
+        picture
+    }
+
+    #[allow(dead_code)]
+    pub fn render_to_file(&self, file_path: &str, style: RenderStyle) -> Result<()> {
+        fs::write(file_path, self.render(style)).context("Failed to write rendered map")
+    }
+
+    fn coloured_glyph(&self, position: (usize, usize), pipe: Pipe, region: TileRegion) -> ColoredString {
+        let glyph = box_glyph(pipe).to_string();
+        match region {
+            TileRegion::Loop if position == self.start_position() => glyph.yellow().bold(),
+            TileRegion::Loop => glyph.bold(),
+            TileRegion::Inside if pipe == Pipe::Ground => "●".green(),
+            TileRegion::Inside => glyph.green().dimmed(),
+            TileRegion::Outside if pipe == Pipe::Ground => "·".blue(),
+            TileRegion::Outside => glyph.blue().dimmed(),
+        }
+    }
+}
+
+fn plain_glyph(pipe: Pipe, region: TileRegion) -> char {
+    match region {
+        TileRegion::Loop => box_glyph(pipe),
+        TileRegion::Inside => 'I',
+        TileRegion::Outside => 'O',
+    }
+}
+
+fn box_glyph(pipe: Pipe) -> char {
+    match pipe {
+        Pipe::Vertical => '│',
+        Pipe::Horizontal => '─',
+        Pipe::NE => '└',
+        Pipe::NW => '┘',
+        Pipe::SW => '┐',
+        Pipe::SE => '┌',
+        Pipe::Ground => ' ',
+        Pipe::Start => 'S',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::input_output::read_file;
+
+    #[test]
+    fn test_render_plain() {
+        let content = read_file("resources/input_day_10_test_e.txt").unwrap();
+        let explorer = Explorer::new(&content).unwrap();
+
+        let expected_picture = "\
+OOOOOOOOOOO
+O┌───────┐O
+O│┌─────┐│O
+O││OOOOO││O
+O││OOOOO││O
+O│└─┐O┌─┘│O
+O│II│O│II│O
+O└──┘O└──┘O
+OOOOOOOOOOO
+";
+        assert_eq!(explorer.render(RenderStyle::Plain), expected_picture);
+    }
+
+    #[test]
+    fn test_render_coloured_marks_junk_and_regions() {
+        colored::control::set_override(true);
+        let content = read_file("resources/input_day_10_test_b.txt").unwrap();
+        let explorer = Explorer::new(&content).unwrap();
+        let picture = explorer.render(RenderStyle::Coloured);
+
+        assert_eq!(picture.lines().count(), 5);
+        assert!(picture.contains(&"┌".yellow().bold().to_string()));
+        assert!(picture.contains(&"┐".green().dimmed().to_string()));
+        assert!(picture.contains(&"─".blue().dimmed().to_string()));
+        assert!(!picture.contains(&"●".green().to_string()));
+    }
+
+    #[test]
+    fn test_render_to_file() {
+        let content = read_file("resources/input_day_10_test_a.txt").unwrap();
+        let explorer = Explorer::new(&content).unwrap();
+        let file_path = std::env::temp_dir().join(format!("aoc_2023_day_10_render_{}.txt", std::process::id()));
+
+        explorer.render_to_file(file_path.to_str().unwrap(), RenderStyle::Plain).unwrap();
+        let picture = fs::read_to_string(&file_path).unwrap();
+        fs::remove_file(&file_path).unwrap();
+
+        assert_eq!(picture, "OOOOO\nO┌─┐O\nO│I│O\nO└─┘O\nOOOOO\n");
+    }
+}
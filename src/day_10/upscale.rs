@@ -0,0 +1,136 @@
+use std::fmt;
+use crate::day_10::explore::Explorer;
+use crate::day_10::pipe::{direction_offset, DIRECTIONS};
+
+const SCALE: usize = 3;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExpandedGrid {
+    width: usize,
+    height: usize,
+    walls: Vec<bool>,
+}
+
+impl ExpandedGrid {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[allow(dead_code)]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_wall(&self, (x, y): (usize, usize)) -> bool {
+        self.walls[y * self.width + x]
+    }
+
+    pub fn flood_from_border(&self) -> Vec<bool> {
+        let mut reached = vec![false; self.walls.len()];
+        let mut stack: Vec<(usize, usize)> = (0..self.width)
+            .flat_map(|x| [(x, 0), (x, self.height - 1)])
+            .chain((0..self.height).flat_map(|y| [(0, y), (self.width - 1, y)]))
+            .collect();
+
+        while let Some((x, y)) = stack.pop() {
+            let index = y * self.width + x;
+            if reached[index] || self.walls[index] {
+                continue;
+            }
+            reached[index] = true;
+
+            for direction in DIRECTIONS {
+                let (dx, dy) = direction_offset(direction);
+                if let (Some(nx), Some(ny)) = (x.checked_add_signed(dx), y.checked_add_signed(dy)) {
+                    if nx < self.width && ny < self.height {
+                        stack.push((nx, ny));
+                    }
+                }
+            }
+        }
+
+        reached
+    }
+}
+
+impl fmt::Display for ExpandedGrid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.walls.chunks(self.width.max(1)) {
+            let line: String = row.iter().map(|&is_wall| if is_wall { '#' } else { '.' }).collect();
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+impl Explorer {
+    pub fn expanded_grid(&self) -> ExpandedGrid {
+        let grid = self.grid();
+        let width = grid.width() * SCALE;
+        let height = grid.height() * SCALE;
+        let mut walls = vec![false; width * height];
+
+        for (x, y) in self.loop_walk() {
+            let (center_x, center_y) = (x * SCALE + 1, y * SCALE + 1);
+            walls[center_y * width + center_x] = true;
+
+            let connections = grid.connections((x, y));
+            for direction in DIRECTIONS.into_iter().filter(|&direction| connections & direction != 0) {
+                let (dx, dy) = direction_offset(direction);
+                let arm_x = center_x.wrapping_add_signed(dx);
+                let arm_y = center_y.wrapping_add_signed(dy);
+                walls[arm_y * width + arm_x] = true;
+            }
+        }
+
+        ExpandedGrid { width, height, walls }
+    }
+
+    pub fn count_enclosed_tiles_by_upscaling(&self) -> usize {
+        let grid = self.grid();
+        let expanded_grid = self.expanded_grid();
+        let outside = expanded_grid.flood_from_border();
+
+        grid.positions()
+            .map(|(x, y)| (x * SCALE + 1, y * SCALE + 1))
+            .filter(|&(center_x, center_y)| {
+                !expanded_grid.is_wall((center_x, center_y)) && !outside[center_y * expanded_grid.width() + center_x]
+            })
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::input_output::read_file;
+
+    #[test]
+    fn test_expanded_grid() {
+        let content = read_file("resources/input_day_10_test_a.txt").unwrap();
+        let explorer = Explorer::new(&content).unwrap();
+        let expanded_grid = explorer.expanded_grid();
+
+        assert_eq!((expanded_grid.width(), expanded_grid.height()), (15, 15));
+        let rows: Vec<String> = expanded_grid.to_string().lines().skip(3).take(5).map(String::from).collect();
+        assert_eq!(rows, vec![
+            "...............",
+            "....#######....",
+            "....#.....#....",
+            "....#.....#....",
+            "....#.....#....",
+        ]);
+    }
+
+    #[test]
+    fn test_squeezing_between_parallel_pipes() {
+        let content = read_file("resources/input_day_10_test_e.txt").unwrap();
+        let explorer = Explorer::new(&content).unwrap();
+        let expanded_grid = explorer.expanded_grid();
+        let outside = expanded_grid.flood_from_border();
+
+        let squeezed_center = (3 * 3 + 1) * expanded_grid.width() + 5 * 3 + 1;
+        assert!(outside[squeezed_center]);
+        assert_eq!(explorer.count_enclosed_tiles_by_upscaling(), 4);
+    }
+}
@@ -1,13 +1,16 @@
 use std::collections::HashMap;
 use crate::day_11::rle_compressed_vector_iterator::RLECompressedVectorIterator;
 
+pub type Cosmos = Vec<Vec<(char, usize, usize)>>;
+pub type GalaxyCataloge = HashMap<usize, (usize, usize)>;
+
 enum Dimension {
     Row,
     Column,
 }
 
 pub struct Parser{
-    pub cosmos: Vec<Vec<(char, usize, usize)>>,
+    pub cosmos: Cosmos,
     expansion_rate: usize
 }
 
@@ -25,7 +28,7 @@ impl Parser {
         }
     }
 
-    pub fn expanded_cosmos_data(&mut self) -> (&Vec<Vec<(char, usize, usize)>>, HashMap<usize, (usize, usize)>) {
+    pub fn expanded_cosmos_data(&mut self) -> (&Cosmos, GalaxyCataloge) {
         self.adjust_for_cosmic_expansion();
         let galaxy_cataloge = self.cataloge_galaxies();
         (&self.cosmos, galaxy_cataloge)
@@ -86,7 +89,7 @@ impl Parser {
         column < self.cosmos[0].len()
     }
 
-    fn cataloge_galaxies(&mut self) -> HashMap<usize, (usize, usize)> {
+    fn cataloge_galaxies(&mut self) -> GalaxyCataloge {
         let mut galaxy_positions = HashMap::new();
         let mut galaxy_number = 1;
 
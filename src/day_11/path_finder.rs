@@ -1,12 +1,13 @@
-use std::collections::HashMap;
+use crate::day_11::parser::{Cosmos, GalaxyCataloge};
 
 pub struct PathFinder{
-    pub raw_cosmos_data: Vec<Vec<(char,usize, usize)>>,
-    pub galaxy_cataloge: HashMap<usize,(usize,usize)>
+    #[allow(dead_code)]
+    pub raw_cosmos_data: Cosmos,
+    pub galaxy_cataloge: GalaxyCataloge
 }
 
 impl PathFinder {
-    pub fn new(cosmos_data: (&Vec<Vec<(char,usize,usize)>>, HashMap<usize, (usize, usize)>)) -> Self {
+    pub fn new(cosmos_data: (&Cosmos, GalaxyCataloge)) -> Self {
 
         let raw_cosmos_data = cosmos_data.0.to_vec();
         let galaxy_cataloge = cosmos_data.1;
@@ -15,6 +16,26 @@ impl PathFinder {
     }
 
     pub fn calculate_sum_of_shortest_distances(&self) -> usize {
+        let (x_coordinates, y_coordinates): (Vec<usize>, Vec<usize>) = self.galaxy_cataloge.values().copied().unzip();
+
+        PathFinder::sum_of_axis_distances(x_coordinates) + PathFinder::sum_of_axis_distances(y_coordinates)
+    }
+
+    fn sum_of_axis_distances(mut coordinates: Vec<usize>) -> usize {
+        coordinates.sort_unstable();
+
+        let mut sum = 0;
+        let mut prefix_sum = 0;
+        for (index, &coordinate) in coordinates.iter().enumerate() {
+            sum += coordinate * index - prefix_sum;
+            prefix_sum += coordinate;
+        }
+
+        sum
+    }
+
+    #[allow(dead_code)]
+    pub fn calculate_sum_of_shortest_distances_pairwise(&self) -> usize {
         let mut sum = 0;
         let num_galaxies = self.galaxy_cataloge.len();
 
@@ -31,8 +52,8 @@ impl PathFinder {
     }
 
     pub fn calculate_shortest_distance(galaxy_a: (usize, usize) , galaxy_b: (usize, usize) ) -> usize {
-        let x_distance = if galaxy_a.0 > galaxy_b.0 { galaxy_a.0 - galaxy_b.0 } else { galaxy_b.0 - galaxy_a.0 };
-        let y_distance = if galaxy_a.1 > galaxy_b.1 { galaxy_a.1 - galaxy_b.1 } else { galaxy_b.1 - galaxy_a.1 };
+        let x_distance = galaxy_a.0.abs_diff(galaxy_b.0);
+        let y_distance = galaxy_a.1.abs_diff(galaxy_b.1);
 
         x_distance + y_distance
     }
@@ -87,5 +108,25 @@ mod tests{
         println!("{:?}", path_finder.galaxy_cataloge.get(&7.to_usize().unwrap()));
         let sum_of_distances = path_finder.calculate_sum_of_shortest_distances();
         assert_eq!(sum_of_distances, expected_distance);
+        assert_eq!(path_finder.calculate_sum_of_shortest_distances_pairwise(), expected_distance);
+    }
+
+    #[test]
+    fn test_sum_of_shortest_distances_matches_pairwise_on_input() {
+        let observatory_data = read_file("resources/input_day_11.txt").unwrap();
+        let mut parser = Parser::new(&observatory_data, 1000000);
+        let path_finder = PathFinder::new(parser.expanded_cosmos_data());
+
+        assert_eq!(path_finder.calculate_sum_of_shortest_distances(), path_finder.calculate_sum_of_shortest_distances_pairwise());
+    }
+
+    #[test]
+    fn test_sum_of_shortest_distances_on_large_catalogue() {
+        let galaxy_count = 200_000;
+        let galaxy_cataloge = (1..=galaxy_count).map(|galaxy| (galaxy, (galaxy, 0))).collect();
+        let path_finder = PathFinder { raw_cosmos_data: Vec::new(), galaxy_cataloge };
+
+        let expected_sum = (galaxy_count - 1) * galaxy_count * (galaxy_count + 1) / 6;
+        assert_eq!(path_finder.calculate_sum_of_shortest_distances(), expected_sum);
     }
 }
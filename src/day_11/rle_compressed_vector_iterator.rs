@@ -27,20 +27,17 @@ impl<'a> Iterator for RLECompressedVectorIterator<'a> {
     type Item = (usize, usize, char);
 
     fn next(&mut self) -> Option<Self::Item> {
-        while let Some((collumn, row, _)) = self.inner_iter.next() {
-            let (char, x_count, y_count) = self.grid[row][collumn];
-            let current_virtual_row = self.virtual_row;
-            let current_virtual_col = self.virtual_col;
-
-            self.virtual_col += x_count;
-            if collumn == self.grid[row].len() - 1 {
-                self.virtual_row += y_count;
-                self.virtual_col = 0;
-            }
-            return Some((current_virtual_col, current_virtual_row, char));
+        let (collumn, row, _) = self.inner_iter.next()?;
+        let (char, x_count, y_count) = self.grid[row][collumn];
+        let current_virtual_row = self.virtual_row;
+        let current_virtual_col = self.virtual_col;
+
+        self.virtual_col += x_count;
+        if collumn == self.grid[row].len() - 1 {
+            self.virtual_row += y_count;
+            self.virtual_col = 0;
         }
-
-        None
+        Some((current_virtual_col, current_virtual_row, char))
     }
 }
 
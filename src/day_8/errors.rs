@@ -1,16 +1,22 @@
+use crate::day_8::validation::ValidationReport;
+
 #[derive(Debug)]
 pub enum GraphError {
     NodeNotFound(String),
-    TargetNotReachedWithinIterations,
+    Unreachable { start: String, cycle: Vec<String> },
+    CommonTerminationUnreachable,
     NeighbourNodeNotFound { node: String, neighbour: String },
+    InvalidGraph(ValidationReport),
 }
 
 impl std::fmt::Display for GraphError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             GraphError::NodeNotFound(node) => write!(f, "Node not found: {}", node),
-            GraphError::TargetNotReachedWithinIterations => write!(f, "Target not reached within maximum waypoint instruction iterations"),
+            GraphError::Unreachable { start, cycle } => write!(f, "Target not reachable from '{}', walk repeats the cycle {}", start, cycle.join(" -> ")),
+            GraphError::CommonTerminationUnreachable => write!(f, "Start nodes never reach target nodes at the same step"),
             GraphError::NeighbourNodeNotFound { node, neighbour } => write!(f, "Neighbour node '{}' not found from '{}'", neighbour, node),
+            GraphError::InvalidGraph(report) => write!(f, "Invalid graph:\n{}", report),
         }
     }
 }
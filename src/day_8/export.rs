@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+use crate::day_8::graph::Graph;
+use crate::day_8::node_selector::NodeSelector;
+
+const HIGHLIGHT_COLOURS: [&str; 6] = ["red", "blue", "darkgreen", "orange", "purple", "brown"];
+
+impl Graph {
+    pub fn to_dot(&self, starts: &NodeSelector, targets: &NodeSelector, highlighted_paths: &[Vec<String>]) -> String {
+        let highlighted_edges = highlighted_edge_colours(highlighted_paths);
+        let mut dot = String::new();
+
+        writeln!(dot, "digraph network {{").unwrap();
+        writeln!(dot, "    node [shape=circle];").unwrap();
+
+        for node in self.nodes() {
+            let is_start = starts.matches(node.name());
+            let is_target = targets.matches(node.name());
+            match (is_start, is_target) {
+                (true, true) => writeln!(dot, "    \"{}\" [style=filled, fillcolor=gold, shape=doublecircle];", node.name()).unwrap(),
+                (true, false) => writeln!(dot, "    \"{}\" [style=filled, fillcolor=palegreen];", node.name()).unwrap(),
+                (false, true) => writeln!(dot, "    \"{}\" [style=filled, fillcolor=salmon, shape=doublecircle];", node.name()).unwrap(),
+                (false, false) => {}
+            }
+        }
+
+        for node in self.nodes() {
+            let mut edges: Vec<(&str, Vec<String>)> = Vec::new();
+            for (index, successor) in node.successors().iter().enumerate() {
+                let label = self.alphabet().label(index);
+                match edges.iter_mut().find(|(neighbour, _)| neighbour == successor) {
+                    Some((_, labels)) => labels.push(label),
+                    None => edges.push((successor, vec![label])),
+                }
+            }
+
+            for (neighbour, labels) in edges {
+                let label = labels.join("/");
+                match highlighted_edges.get(&(node.name(), neighbour)) {
+                    Some(colour) => writeln!(dot, "    \"{}\" -> \"{}\" [label=\"{}\", color={}, penwidth=2.5];", node.name(), neighbour, label, colour).unwrap(),
+                    None => writeln!(dot, "    \"{}\" -> \"{}\" [label=\"{}\"];", node.name(), neighbour, label).unwrap(),
+                }
+            }
+        }
+
+        writeln!(dot, "}}").unwrap();
+        dot
+    }
+
+    pub fn to_adjacency_text(&self, starts: &NodeSelector, targets: &NodeSelector) -> String {
+        let mut text = String::new();
+
+        for node in self.nodes() {
+            write!(text, "{}", node.name()).unwrap();
+            for (index, successor) in node.successors().iter().enumerate() {
+                write!(text, " {}:{}", self.alphabet().label(index), successor).unwrap();
+            }
+            if starts.matches(node.name()) {
+                write!(text, " start").unwrap();
+            }
+            if targets.matches(node.name()) {
+                write!(text, " target").unwrap();
+            }
+            writeln!(text).unwrap();
+        }
+
+        text
+    }
+}
+
+fn highlighted_edge_colours(highlighted_paths: &[Vec<String>]) -> HashMap<(&str, &str), &'static str> {
+    let mut edge_colours = HashMap::new();
+
+    for (index, path) in highlighted_paths.iter().enumerate() {
+        let colour = HIGHLIGHT_COLOURS[index % HIGHLIGHT_COLOURS.len()];
+        for edge in path.windows(2) {
+            edge_colours.entry((edge[0].as_str(), edge[1].as_str())).or_insert(colour);
+        }
+    }
+
+    edge_colours
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_8::instruction_alphabet::InstructionAlphabet;
+    use crate::utils::input_output::read_file;
+
+    #[test]
+    fn test_to_dot() {
+        let graph = Graph::new(&read_file("resources/input_day_8_test_b.txt").unwrap()).unwrap();
+        let path = graph.trace_path_to_target("AAA", &NodeSelector::names(&["ZZZ"])).unwrap();
+        let dot = graph.to_dot(&NodeSelector::names(&["AAA"]), &NodeSelector::names(&["ZZZ"]), &[path]);
+
+        let expected_dot = "digraph network {
+    node [shape=circle];
+    \"AAA\" [style=filled, fillcolor=palegreen];
+    \"ZZZ\" [style=filled, fillcolor=salmon, shape=doublecircle];
+    \"AAA\" -> \"BBB\" [label=\"L/R\", color=red, penwidth=2.5];
+    \"BBB\" -> \"AAA\" [label=\"L\", color=red, penwidth=2.5];
+    \"BBB\" -> \"ZZZ\" [label=\"R\", color=red, penwidth=2.5];
+    \"ZZZ\" -> \"ZZZ\" [label=\"L/R\"];
+}
+";
+        assert_eq!(dot, expected_dot);
+    }
+
+    #[test]
+    fn test_to_dot_with_ghost_cycles() {
+        let graph = Graph::new(&read_file("resources/input_day_8_test_c.txt").unwrap()).unwrap();
+        let cycles = vec![graph.trace_ghost_cycle("11A").unwrap(), graph.trace_ghost_cycle("22A").unwrap()];
+        let dot = graph.to_dot(&NodeSelector::pattern("*A"), &NodeSelector::pattern("*Z"), &cycles);
+
+        assert!(dot.contains("\"11B\" -> \"11Z\" [label=\"R\", color=red, penwidth=2.5];"));
+        assert!(dot.contains("\"22C\" -> \"22Z\" [label=\"L/R\", color=blue, penwidth=2.5];"));
+        assert!(dot.contains("\"11A\" -> \"11B\" [label=\"L\"];"));
+    }
+
+    #[test]
+    fn test_to_dot_with_n_ary_nodes() {
+        let graph = Graph::with_alphabet(&read_file("resources/input_day_8_test_g.txt").unwrap(), InstructionAlphabet::new("LMR")).unwrap();
+        let dot = graph.to_dot(&NodeSelector::names(&["AAA"]), &NodeSelector::names(&["ZZZ"]), &[]);
+
+        assert!(dot.contains("\"AAA\" -> \"DDD\" [label=\"R\"];"));
+        assert!(dot.contains("\"BBB\" -> \"AAA\" [label=\"L/R\"];"));
+    }
+
+    #[test]
+    fn test_to_adjacency_text() {
+        let graph = Graph::new(&read_file("resources/input_day_8_test_b.txt").unwrap()).unwrap();
+        let text = graph.to_adjacency_text(&NodeSelector::names(&["AAA"]), &NodeSelector::names(&["ZZZ"]));
+
+        assert_eq!(text, "AAA L:BBB R:BBB start\nBBB L:AAA R:ZZZ\nZZZ L:ZZZ R:ZZZ target\n");
+    }
+}
@@ -0,0 +1,115 @@
+use crate::utils::math::combine_congruences;
+
+#[derive(Debug, PartialEq)]
+pub struct GhostCycle {
+    pre_period: usize,
+    cycle_length: usize,
+    target_steps: Vec<usize>,
+}
+
+impl GhostCycle {
+    pub fn new(pre_period: usize, cycle_length: usize, target_steps: Vec<usize>) -> Self {
+        GhostCycle {
+            pre_period,
+            cycle_length,
+            target_steps,
+        }
+    }
+
+    pub fn pre_period(&self) -> usize {
+        self.pre_period
+    }
+
+    pub fn cycle_length(&self) -> usize {
+        self.cycle_length
+    }
+
+    pub fn is_target_at(&self, step: usize) -> bool {
+        if step < self.pre_period {
+            self.target_steps.contains(&step)
+        } else {
+            let step_in_cycle = self.pre_period + (step - self.pre_period) % self.cycle_length;
+            self.target_steps.contains(&step_in_cycle)
+        }
+    }
+
+    fn cycle_residues(&self) -> Vec<i128> {
+        self.target_steps.iter()
+            .filter(|&&step| step >= self.pre_period)
+            .map(|&step| (step % self.cycle_length) as i128)
+            .collect()
+    }
+}
+
+pub fn earliest_common_target_step(cycles: &[GhostCycle]) -> Option<usize> {
+    let longest_pre_period = cycles.iter().map(GhostCycle::pre_period).max()?;
+
+    if let Some(step) = (1..longest_pre_period).find(|&step| cycles.iter().all(|cycle| cycle.is_target_at(step))) {
+        return Some(step);
+    }
+
+    let mut congruences = vec![(0, 1)];
+    for cycle in cycles {
+        let cycle_length = cycle.cycle_length() as i128;
+        let residues = cycle.cycle_residues();
+
+        congruences = congruences.iter()
+            .flat_map(|&congruence| residues.iter()
+                .filter_map(move |&residue| combine_congruences(congruence, (residue, cycle_length))))
+            .collect();
+        congruences.sort();
+        congruences.dedup();
+
+        if congruences.is_empty() {
+            return None;
+        }
+    }
+
+    let lower_bound = longest_pre_period.max(1) as i128;
+    congruences.into_iter()
+        .map(|(residue, modulus)| {
+            if residue >= lower_bound {
+                residue
+            } else {
+                residue + (lower_bound - residue + modulus - 1) / modulus * modulus
+            }
+        })
+        .min()
+        .and_then(|step| usize::try_from(step).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use super::*;
+
+    #[rstest]
+    #[case(0, true)]
+    #[case(1, false)]
+    #[case(3, true)]
+    #[case(4, false)]
+    #[case(5, true)]
+    #[case(101, true)]
+    fn test_is_target_at(#[case] step: usize, #[case] expected: bool) {
+        let cycle = GhostCycle::new(2, 2, vec![0, 3]);
+        assert_eq!(cycle.is_target_at(step), expected);
+    }
+
+    #[test]
+    fn test_earliest_common_target_step_with_offsets() {
+        let cycles = vec![GhostCycle::new(2, 2, vec![3]), GhostCycle::new(1, 3, vec![1])];
+        assert_eq!(earliest_common_target_step(&cycles), Some(7));
+    }
+
+    #[test]
+    fn test_earliest_common_target_step_within_pre_period() {
+        let cycles = vec![GhostCycle::new(5, 4, vec![2, 6]), GhostCycle::new(3, 2, vec![2, 3])];
+        assert_eq!(earliest_common_target_step(&cycles), Some(2));
+    }
+
+    #[test]
+    fn test_earliest_common_target_step_without_solution() {
+        let cycles = vec![GhostCycle::new(0, 2, vec![1]), GhostCycle::new(1, 2, vec![2])];
+        assert_eq!(earliest_common_target_step(&cycles), None);
+    }
+}
@@ -1,91 +1,209 @@
 use std::collections::{HashMap, HashSet};
 use anyhow::{Result};
 use crate::day_8::errors::{Day8ParsingError, GraphError};
-use crate::utils::math::least_common_multiple;
 use crate::day_8::parser::{parse_nodes, parse_waypoint_instructions};
 use crate::day_8::node::Node;
+use crate::day_8::ghost_cycle::{earliest_common_target_step, GhostCycle};
+use crate::day_8::node_selector::NodeSelector;
+use crate::day_8::validation::ValidationReport;
+use crate::day_8::walk::{LockstepWalk, Walk};
+use crate::day_8::instruction_alphabet::InstructionAlphabet;
 
 pub struct Graph {
     nodes: HashMap<String, Node>,
     waypoint_instructions: String,
+    alphabet: InstructionAlphabet,
 }
 
 impl Graph {
     pub fn new(input: &str) -> Result<Graph> {
-        let waypoint_instructions = parse_waypoint_instructions(input)?;
+        Graph::with_alphabet(input, InstructionAlphabet::default())
+    }
+
+    pub fn with_alphabet(input: &str, alphabet: InstructionAlphabet) -> Result<Graph> {
+        let waypoint_instructions = parse_waypoint_instructions(input, &alphabet)?;
         let parsed_nodes = parse_nodes(input)?;
+        let required_successors = waypoint_instructions.chars()
+            .filter_map(|instruction| alphabet.successor_index(instruction))
+            .max()
+            .map_or(0, |index| index + 1);
+
         let mut nodes = HashMap::new();
         for node in parsed_nodes {
+            if node.successors().len() < required_successors {
+                return Err(Day8ParsingError::InvalidConnectionCount.into());
+            }
             nodes.insert(node.name().to_string(), node);
         }
 
         Ok(Graph {
             nodes,
             waypoint_instructions,
+            alphabet,
         })
     }
 
-    pub fn count_steps_to_target(&self, start: &str, target: &str, max_waypoint_iterations: usize) -> Result<usize> {
-        let mut current_node = start;
-        let mut current_iteration = 0;
-        let mut steps = 0;
-        let instruction_len = self.waypoint_instructions.chars().count();
+    pub fn new_strict(input: &str, starts: &NodeSelector, targets: &NodeSelector) -> Result<Graph> {
+        let report = Graph::validate(input, starts, targets)?;
+        if report.has_errors() {
+            return Err(GraphError::InvalidGraph(report).into());
+        }
+        if report.has_warnings() {
+            log::warn!("{}", report);
+        }
 
-        while current_iteration < max_waypoint_iterations {
-            for i in 0..instruction_len {
-                let next_node = self.get_next_node(current_node, self.waypoint_instructions.chars().nth(i).unwrap())?;
-                current_node = next_node.name();
+        Graph::new(input)
+    }
 
-                if current_node == target {
-                    return Ok(steps);
-                }
-                steps += 1;
-            }
-            current_iteration += 1;
+    pub fn validate(input: &str, starts: &NodeSelector, targets: &NodeSelector) -> Result<ValidationReport> {
+        parse_waypoint_instructions(input, &InstructionAlphabet::default())?;
+        let parsed_nodes = parse_nodes(input)?;
+        Ok(ValidationReport::new(&parsed_nodes, starts, targets))
+    }
+
+    pub fn select_nodes(&self, selector: &NodeSelector) -> Vec<&str> {
+        let mut selected_nodes: Vec<&str> = self.nodes.keys()
+            .map(String::as_str)
+            .filter(|name| selector.matches(name))
+            .collect();
+        selected_nodes.sort();
+        selected_nodes
+    }
+
+    pub fn waypoint_instructions(&self) -> &str {
+        &self.waypoint_instructions
+    }
+
+    #[allow(dead_code)]
+    pub fn alphabet(&self) -> &InstructionAlphabet {
+        &self.alphabet
+    }
+
+    #[allow(dead_code)]
+    pub fn walk(&self, start: &str) -> Result<Walk<'_>> {
+        let start_node = self.nodes.get(start)
+            .ok_or_else(|| GraphError::NodeNotFound(start.to_string()))?;
+        Ok(Walk::new(self, start_node.name()))
+    }
+
+    #[allow(dead_code)]
+    pub fn walk_lockstep(&self, starts: &NodeSelector) -> Result<LockstepWalk<'_>> {
+        let walkers = self.select_nodes(starts).into_iter()
+            .map(|start| self.walk(start))
+            .collect::<Result<Vec<Walk>>>()?;
+        Ok(LockstepWalk::new(walkers))
+    }
+
+    #[allow(dead_code)]
+    pub fn nodes(&self) -> Vec<&Node> {
+        let mut nodes: Vec<&Node> = self.nodes.values().collect();
+        nodes.sort_by(|a, b| a.name().cmp(b.name()));
+        nodes
+    }
+
+    pub fn count_steps_to_target(&self, start: &str, targets: &NodeSelector) -> Result<usize> {
+        Ok(self.trace_path_to_target(start, targets)?.len() - 1)
+    }
+
+    pub fn trace_path_to_target(&self, start: &str, targets: &NodeSelector) -> Result<Vec<String>> {
+        let (visited_nodes, pre_period) = self.walk_until_repeat(start)?;
+
+        match visited_nodes.iter().skip(1).position(|node| targets.matches(node)) {
+            Some(target_index) => Ok(visited_nodes[..=target_index + 1].iter().map(|node| node.to_string()).collect()),
+            None => Err(Graph::unreachable_error(start, &visited_nodes[pre_period..]).into()),
         }
+    }
 
-        Err(GraphError::TargetNotReachedWithinIterations.into())
+    #[allow(dead_code)]
+    pub fn trace_ghost_cycle(&self, start: &str) -> Result<Vec<String>> {
+        let (visited_nodes, pre_period) = self.walk_until_repeat(start)?;
+        let mut cycle: Vec<String> = visited_nodes[pre_period..].iter().map(|node| node.to_string()).collect();
+        cycle.push(visited_nodes[pre_period].to_string());
+        Ok(cycle)
     }
 
-    pub fn count_steps_to_common_termination_condition(&self) ->  Result<usize> {
-        let distances = self.find_target_distances()?;
-        let mut cycle_lengths: Vec<usize> = Vec::new();
+    #[allow(dead_code)]
+    pub fn count_steps_from_each(&self, starts: &NodeSelector, targets: &NodeSelector) -> Result<HashMap<String, usize>> {
+        self.select_nodes(starts).into_iter()
+            .map(|start| Ok((start.to_string(), self.count_steps_to_target(start, targets)?)))
+            .collect()
+    }
 
-        for (_, target_distances) in distances {
-            if let Some((_, first_distance)) = target_distances.get(0) {
-                if let Some((_, second_distance)) = target_distances.get(1) {
-                    if first_distance==second_distance{
-                        cycle_lengths.push(*first_distance);
-                    } else {
-                        cycle_lengths.push(*first_distance + *second_distance)
-                    }
-                }
+    pub fn count_steps_to_common_termination_condition(&self, starts: &NodeSelector, targets: &NodeSelector) ->  Result<usize> {
+        let ghost_cycles = self.select_nodes(starts).into_iter()
+            .map(|start| self.find_ghost_cycle(start, targets))
+            .collect::<Result<Vec<GhostCycle>>>()?;
+
+        earliest_common_target_step(&ghost_cycles)
+            .ok_or_else(|| GraphError::CommonTerminationUnreachable.into())
+    }
+
+    pub fn find_ghost_cycle(&self, start: &str, targets: &NodeSelector) -> Result<GhostCycle> {
+        let (visited_nodes, pre_period) = self.walk_until_repeat(start)?;
+        let target_steps = visited_nodes.iter()
+            .enumerate()
+            .filter(|(_, node)| targets.matches(node))
+            .map(|(steps, _)| steps)
+            .collect();
+
+        Ok(GhostCycle::new(pre_period, visited_nodes.len() - pre_period, target_steps))
+    }
+
+    fn walk_until_repeat(&self, start: &str) -> Result<(Vec<&str>, usize)> {
+        let instructions: Vec<char> = self.waypoint_instructions.chars().collect();
+        let mut first_visits: HashMap<(&str, usize), usize> = HashMap::new();
+        let mut visited_nodes = Vec::new();
+        let mut current_node = self.nodes.get(start)
+            .ok_or_else(|| GraphError::NodeNotFound(start.to_string()))?
+            .name();
+
+        loop {
+            let steps = visited_nodes.len();
+            let instruction_index = steps % instructions.len();
+            if let Some(&pre_period) = first_visits.get(&(current_node, instruction_index)) {
+                return Ok((visited_nodes, pre_period));
             }
-        }
+            first_visits.insert((current_node, instruction_index), steps);
+            visited_nodes.push(current_node);
 
-        Ok(cycle_lengths.into_iter().reduce(|a, b| least_common_multiple(a, b)).unwrap_or(0))
+            current_node = self.get_next_node(current_node, instructions[instruction_index])?.name();
+        }
     }
 
-    pub fn find_target_distances(&self) -> Result<HashMap<String, Vec<(String, usize)>>> {
+    #[allow(dead_code)]
+    pub fn find_target_distances(&self, starts: &NodeSelector, targets: &NodeSelector) -> Result<HashMap<String, Vec<(String, usize)>>> {
         let mut distances = HashMap::new();
-        let start_nodes: Vec<_> = self.nodes.values()
-            .filter(|node| node.is_start_node())
+        let start_nodes: Vec<&Node> = self.select_nodes(starts).into_iter()
+            .filter_map(|start| self.nodes.get(start))
             .collect();
 
+        let instructions: Vec<char> = self.waypoint_instructions.chars().collect();
+
         for node in start_nodes {
             let mut visited_targets = HashSet::new();
+            let mut first_visits: HashMap<(&str, usize), usize> = HashMap::new();
+            let mut visited_nodes = Vec::new();
             let mut current_node_name = node.name();
             let mut steps_since_last_target = 0;
             let mut total_steps = 0;
 
             loop {
-                let instruction = self.waypoint_instructions.chars().nth(total_steps % self.waypoint_instructions.len()).unwrap();
-                let next_node = self.get_next_node(current_node_name, instruction)?;
+                let instruction_index = total_steps % instructions.len();
+                if let Some(&first_visit) = first_visits.get(&(current_node_name, instruction_index)) {
+                    if distances.contains_key(node.name()) {
+                        break;
+                    }
+                    return Err(Graph::unreachable_error(node.name(), &visited_nodes[first_visit..]).into());
+                }
+                first_visits.insert((current_node_name, instruction_index), total_steps);
+                visited_nodes.push(current_node_name);
+
+                let next_node = self.get_next_node(current_node_name, instructions[instruction_index])?;
                 current_node_name = next_node.name();
                 total_steps += 1;
                 steps_since_last_target += 1;
 
-                if next_node.is_target_node() {
+                if targets.matches(next_node.name()) {
                     if visited_targets.contains(current_node_name) {
                         distances.entry(node.name().to_string())
                             .or_insert_with(Vec::new)
@@ -104,23 +222,30 @@ impl Graph {
         Ok(distances)
     }
 
-    fn get_next_node(&self, current_node: &str, instruction: char) -> Result<&Node> {
+    fn unreachable_error(start: &str, cycle: &[&str]) -> GraphError {
+        GraphError::Unreachable {
+            start: start.to_string(),
+            cycle: cycle.iter().map(|node| node.to_string()).collect(),
+        }
+    }
+
+    pub fn get_next_node(&self, current_node: &str, instruction: char) -> Result<&Node> {
         let node = self.nodes.get(current_node)
             .ok_or_else(|| GraphError::NodeNotFound(current_node.to_string()))?;
 
-        match instruction {
-            'R' => self.nodes.get(node.right_neighbour())
-                .ok_or_else(|| GraphError::NeighbourNodeNotFound { node: current_node.to_string(), neighbour: "right".to_string() }.into()),
-            'L' => self.nodes.get(node.left_neighbour())
-                .ok_or_else(|| GraphError::NeighbourNodeNotFound { node: current_node.to_string(), neighbour: "left".to_string() }.into()),
-            _ => Err(Day8ParsingError::InvalidWaypointInstruction.into()),
-        }
+        let successor_index = self.alphabet.successor_index(instruction)
+            .ok_or(Day8ParsingError::InvalidWaypointInstruction)?;
+
+        node.successor(successor_index)
+            .and_then(|successor| self.nodes.get(successor))
+            .ok_or_else(|| GraphError::NeighbourNodeNotFound { node: current_node.to_string(), neighbour: self.alphabet.label(successor_index) }.into())
     }
 
 }
 
 #[cfg(test)]
 mod tests {
+    use rstest::rstest;
     use super::*;
     use crate::utils::input_output::read_file;
 
@@ -139,20 +264,71 @@ mod tests {
         let graph = Graph::new(&read_file(testfile_path).unwrap()).unwrap();
 
         let start = "AAA";
-        let target = "ZZZ";
-        let max_cycles = 10;
-
-        let traversal_result = graph.count_steps_to_target(start, target, max_cycles)
+        let target = NodeSelector::names(&["ZZZ"]);
+        let traversal_result = graph.count_steps_to_target(start, &target)
             .expect("Traversal failed");
 
         assert_eq!(traversal_result, expected_steps);
     }
 
+    #[test]
+    fn test_count_steps_to_unreachable_target() {
+        let graph = Graph::new(&read_file("resources/input_day_8_test_a.txt").unwrap()).unwrap();
+        let error = graph.count_steps_to_target("BBB", &NodeSelector::names(&["ZZZ"])).unwrap_err();
+
+        match error.downcast_ref::<GraphError>() {
+            Some(GraphError::Unreachable { start, cycle }) => {
+                assert_eq!(start, "BBB");
+                assert_eq!(cycle, &vec!["EEE".to_string(), "EEE".to_string()]);
+            }
+            _ => panic!("Expected unreachable error, got {}", error),
+        }
+    }
+
+    #[test]
+    fn test_find_target_distances_for_unreachable_target() {
+        let graph = Graph::new(&read_file("resources/input_day_8_test_c.txt").unwrap()).unwrap();
+        let result = graph.find_target_distances(&NodeSelector::names(&["11A"]), &NodeSelector::names(&["22Z"]));
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    #[case("resources/input_day_8_test_g.txt", InstructionAlphabet::new("LMR"), 3)]
+    #[case("resources/input_day_8_test_h.txt", InstructionAlphabet::numeric(), 5)]
+    fn test_count_steps_with_alphabet(#[case] input_file: &str, #[case] alphabet: InstructionAlphabet, #[case] expected_steps: usize) {
+        let graph = Graph::with_alphabet(&read_file(input_file).unwrap(), alphabet).unwrap();
+        let steps = graph.count_steps_to_target("AAA", &NodeSelector::names(&["ZZZ"])).unwrap();
+        assert_eq!(steps, expected_steps);
+    }
+
+    #[test]
+    fn test_with_alphabet_rejects_missing_successors() {
+        let result = Graph::with_alphabet("LMR\n\nAAA = (BBB, CCC)\nBBB = (AAA, AAA)\nCCC = (AAA, AAA)", InstructionAlphabet::new("LMR"));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_new_strict() {
+        let starts = NodeSelector::names(&["AAA"]);
+        let targets = NodeSelector::names(&["ZZZ"]);
+
+        assert!(Graph::new_strict(&read_file("resources/input_day_8_test_a.txt").unwrap(), &starts, &targets).is_ok());
+        assert!(Graph::new(&read_file("resources/input_day_8_test_f.txt").unwrap()).is_ok());
+
+        match Graph::new_strict(&read_file("resources/input_day_8_test_f.txt").unwrap(), &starts, &targets) {
+            Err(error) => match error.downcast_ref::<GraphError>() {
+                Some(GraphError::InvalidGraph(report)) => assert_eq!(report.duplicate_nodes, vec!["BBB"]),
+                _ => panic!("Expected invalid graph error, got {}", error),
+            },
+            Ok(_) => panic!("Expected invalid graph error"),
+        }
+    }
+
     #[test]
     fn test_find_target_distances() {
 
         let graph = Graph::new(&read_file("resources/input_day_8_test_c.txt").unwrap()).unwrap();
-        let distances = graph.find_target_distances().unwrap();
+        let distances = graph.find_target_distances(&NodeSelector::pattern("*A"), &NodeSelector::pattern("*Z")).unwrap();
 
         for (start_node, target_distances) in distances {
             println!("Start Node: {}", start_node);
@@ -162,11 +338,43 @@ mod tests {
         }
     }
 
+    #[rstest]
+    #[case("resources/input_day_8_test_c.txt", 6)]
+    #[case("resources/input_day_8_test_d.txt", 7)]
+    fn test_find_overall_step(#[case] input_file: &str, #[case] expected_steps: usize) {
+        let graph = Graph::new(&read_file(input_file).unwrap()).unwrap();
+        let step_count = graph.count_steps_to_common_termination_condition(&NodeSelector::pattern("*A"), &NodeSelector::pattern("*Z")).unwrap();
+        assert_eq!(step_count, expected_steps);
+    }
+
     #[test]
-    fn test_find_overall_step() {
+    fn test_find_overall_step_without_common_termination() {
+        let graph = Graph::new(&read_file("resources/input_day_8_test_e.txt").unwrap()).unwrap();
+        assert!(graph.count_steps_to_common_termination_condition(&NodeSelector::pattern("*A"), &NodeSelector::pattern("*Z")).is_err());
+    }
+
+    #[rstest]
+    #[case("11A", GhostCycle::new(2, 2, vec![3]))]
+    #[case("22A", GhostCycle::new(1, 3, vec![1]))]
+    fn test_find_ghost_cycle(#[case] start: &str, #[case] expected_cycle: GhostCycle) {
+        let graph = Graph::new(&read_file("resources/input_day_8_test_d.txt").unwrap()).unwrap();
+        assert_eq!(graph.find_ghost_cycle(start, &NodeSelector::pattern("*Z")).unwrap(), expected_cycle);
+    }
+
+    #[test]
+    fn test_select_nodes() {
         let graph = Graph::new(&read_file("resources/input_day_8_test_c.txt").unwrap()).unwrap();
-        let step_count = graph.count_steps_to_common_termination_condition().unwrap();
-        assert_eq!(step_count, 6);
+        assert_eq!(graph.select_nodes(&NodeSelector::pattern("2?B")), vec!["22B"]);
+        assert_eq!(graph.select_nodes(&NodeSelector::predicate(|name| name.ends_with('Z'))), vec!["11Z", "22Z"]);
+    }
+
+    #[test]
+    fn test_count_steps_from_each() {
+        let graph = Graph::new(&read_file("resources/input_day_8_test_c.txt").unwrap()).unwrap();
+        let steps = graph.count_steps_from_each(&NodeSelector::pattern("22?"), &NodeSelector::names(&["22Z"])).unwrap();
+        let expected_steps = HashMap::from([("22A".to_string(), 3), ("22B".to_string(), 2), ("22C".to_string(), 1), ("22Z".to_string(), 3)]);
+
+        assert_eq!(steps, expected_steps);
     }
 }
 
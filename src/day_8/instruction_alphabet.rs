@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+pub struct InstructionAlphabet {
+    successor_indices: HashMap<char, usize>,
+}
+
+impl InstructionAlphabet {
+    pub fn new(symbols: &str) -> Self {
+        InstructionAlphabet {
+            successor_indices: symbols.chars().enumerate().map(|(index, symbol)| (symbol, index)).collect(),
+        }
+    }
+
+    pub fn left_right() -> Self {
+        InstructionAlphabet::new("LR")
+    }
+
+    #[allow(dead_code)]
+    pub fn numeric() -> Self {
+        InstructionAlphabet::new("0123456789")
+    }
+
+    pub fn successor_index(&self, symbol: char) -> Option<usize> {
+        self.successor_indices.get(&symbol).copied()
+    }
+
+    pub fn contains(&self, symbol: char) -> bool {
+        self.successor_indices.contains_key(&symbol)
+    }
+
+    pub fn label(&self, successor_index: usize) -> String {
+        let mut symbols: Vec<char> = self.successor_indices.iter()
+            .filter(|&(_, &index)| index == successor_index)
+            .map(|(&symbol, _)| symbol)
+            .collect();
+        symbols.sort();
+
+        match symbols.first() {
+            Some(symbol) => symbol.to_string(),
+            None => successor_index.to_string(),
+        }
+    }
+}
+
+impl Default for InstructionAlphabet {
+    fn default() -> Self {
+        InstructionAlphabet::left_right()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use super::*;
+
+    #[rstest]
+    #[case(InstructionAlphabet::left_right(), 'L', Some(0))]
+    #[case(InstructionAlphabet::left_right(), 'R', Some(1))]
+    #[case(InstructionAlphabet::left_right(), 'M', None)]
+    #[case(InstructionAlphabet::new("LMR"), 'M', Some(1))]
+    #[case(InstructionAlphabet::new("LMR"), 'R', Some(2))]
+    #[case(InstructionAlphabet::numeric(), '3', Some(3))]
+    fn test_successor_index(#[case] alphabet: InstructionAlphabet, #[case] symbol: char, #[case] expected: Option<usize>) {
+        assert_eq!(alphabet.successor_index(symbol), expected);
+    }
+
+    #[test]
+    fn test_label() {
+        let alphabet = InstructionAlphabet::new("LMR");
+        assert_eq!(alphabet.label(1), "M");
+        assert_eq!(alphabet.label(3), "3");
+    }
+}
@@ -2,16 +2,26 @@ mod node;
 mod graph;
 mod parser;
 mod errors;
+mod ghost_cycle;
+mod node_selector;
+mod validation;
+mod walk;
+mod instruction_alphabet;
+#[allow(dead_code)]
+mod oracle;
+#[allow(dead_code)]
+mod export;
 
 use crate::utils::input_output::read_file;
 use graph::Graph;
+use node_selector::NodeSelector;
 
 pub fn solve_day_8() -> Result<(usize, usize), Box<dyn std::error::Error>> {
     let graph_input = read_file("resources/input_day_8.txt")?;
-    let graph = Graph::new(&graph_input)?;
+    let graph = Graph::new_strict(&graph_input, &NodeSelector::names(&["AAA"]), &NodeSelector::names(&["ZZZ"]))?;
 
-    let part_1_steps = graph.count_steps_to_target("AAA", "ZZZ", 10000)?;
-    let part_2_steps = graph.count_steps_to_common_termination_condition()?;
+    let part_1_steps = graph.count_steps_to_target("AAA", &NodeSelector::names(&["ZZZ"]))?;
+    let part_2_steps = graph.count_steps_to_common_termination_condition(&NodeSelector::pattern("*A"), &NodeSelector::pattern("*Z"))?;
 
     Ok((part_1_steps, part_2_steps))
 }
@@ -1,16 +1,15 @@
+
 #[derive(Debug, PartialEq)]
 pub struct Node {
     name: String,
-    left_neighbour: String,
-    right_neighbour: String,
+    successors: Vec<String>,
 }
 
 impl Node {
-    pub fn new(name: &str, left_neighbour: &str, right_neighbour: &str) -> Self {
+    pub fn new(name: &str, successors: &[&str]) -> Self {
         Node {
             name: name.to_string(),
-            left_neighbour: left_neighbour.to_string(),
-            right_neighbour: right_neighbour.to_string(),
+            successors: successors.iter().map(|successor| successor.to_string()).collect(),
         }
     }
 
@@ -18,15 +17,11 @@ impl Node {
         &self.name
     }
 
-    pub fn left_neighbour(&self) -> &str {
-        &self.left_neighbour
+    pub fn successors(&self) -> &[String] {
+        &self.successors
     }
 
-    pub fn right_neighbour(&self) -> &str {
-        &self.right_neighbour
+    pub fn successor(&self, index: usize) -> Option<&str> {
+        self.successors.get(index).map(String::as_str)
     }
-
-    pub fn is_start_node(&self) -> bool { self.name.ends_with('A') }
-
-    pub fn is_target_node(&self) -> bool { self.name.ends_with('Z') }
 }
@@ -0,0 +1,75 @@
+use std::collections::HashSet;
+
+pub enum NodeSelector {
+    Names(HashSet<String>),
+    Pattern(Vec<char>),
+    #[allow(dead_code)]
+    Predicate(Box<dyn Fn(&str) -> bool>),
+}
+
+impl NodeSelector {
+    pub fn names<S: AsRef<str>>(names: &[S]) -> Self {
+        NodeSelector::Names(names.iter().map(|name| name.as_ref().to_string()).collect())
+    }
+
+    pub fn pattern(pattern: &str) -> Self {
+        NodeSelector::Pattern(pattern.chars().collect())
+    }
+
+    #[allow(dead_code)]
+    pub fn predicate<F: Fn(&str) -> bool + 'static>(predicate: F) -> Self {
+        NodeSelector::Predicate(Box::new(predicate))
+    }
+
+    pub fn matches(&self, name: &str) -> bool {
+        match self {
+            NodeSelector::Names(names) => names.contains(name),
+            NodeSelector::Pattern(pattern) => matches_pattern(pattern, &name.chars().collect::<Vec<char>>()),
+            NodeSelector::Predicate(predicate) => predicate(name),
+        }
+    }
+}
+
+fn matches_pattern(pattern: &[char], name: &[char]) -> bool {
+    match (pattern.first(), name.first()) {
+        (None, None) => true,
+        (Some('*'), _) => matches_pattern(&pattern[1..], name) || (!name.is_empty() && matches_pattern(pattern, &name[1..])),
+        (Some('?'), Some(_)) => matches_pattern(&pattern[1..], &name[1..]),
+        (Some(expected), Some(actual)) if expected == actual => matches_pattern(&pattern[1..], &name[1..]),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use super::*;
+
+    #[rstest]
+    #[case("*A", "AAA", true)]
+    #[case("*A", "11A", true)]
+    #[case("*A", "AAZ", false)]
+    #[case("??Z", "11Z", true)]
+    #[case("??Z", "1Z", false)]
+    #[case("1*Z", "11Z", true)]
+    #[case("1*Z", "22Z", false)]
+    #[case("AAA", "AAA", true)]
+    #[case("*", "", true)]
+    fn test_pattern_matches(#[case] pattern: &str, #[case] name: &str, #[case] expected: bool) {
+        assert_eq!(NodeSelector::pattern(pattern).matches(name), expected);
+    }
+
+    #[test]
+    fn test_names_matches() {
+        let selector = NodeSelector::names(&["AAA", "ZZZ"]);
+        assert!(selector.matches("ZZZ"));
+        assert!(!selector.matches("ZZA"));
+    }
+
+    #[test]
+    fn test_predicate_matches() {
+        let selector = NodeSelector::predicate(|name| name.starts_with('2'));
+        assert!(selector.matches("22B"));
+        assert!(!selector.matches("11B"));
+    }
+}
@@ -0,0 +1,166 @@
+use std::collections::HashSet;
+use std::thread;
+use anyhow::Result;
+use crate::day_8::errors::GraphError;
+use crate::day_8::graph::Graph;
+use crate::day_8::node_selector::NodeSelector;
+
+const SIMULATION_CHUNK_LENGTH: usize = 1 << 16;
+
+#[derive(Debug, PartialEq)]
+pub enum CrossCheck {
+    Agreement(usize),
+    Disagreement { fast_solver: Option<usize>, simulation: Option<usize> },
+    Inconclusive { fast_solver: Option<usize>, step_budget: usize },
+}
+
+pub fn cross_check(graph: &Graph, starts: &NodeSelector, targets: &NodeSelector, step_budget: usize, threads: usize) -> Result<CrossCheck> {
+    let fast_solver = match graph.count_steps_to_common_termination_condition(starts, targets) {
+        Ok(steps) => Some(steps),
+        Err(error) => match error.downcast_ref::<GraphError>() {
+            Some(GraphError::CommonTerminationUnreachable) => None,
+            _ => return Err(error),
+        },
+    };
+    let simulation = simulate_common_termination(graph, starts, targets, step_budget, threads)?;
+
+    Ok(match (fast_solver, simulation) {
+        (Some(fast_steps), Some(simulated_steps)) if fast_steps == simulated_steps => CrossCheck::Agreement(fast_steps),
+        (Some(fast_steps), None) if fast_steps > step_budget => CrossCheck::Inconclusive { fast_solver, step_budget },
+        (None, None) => CrossCheck::Inconclusive { fast_solver, step_budget },
+        _ => CrossCheck::Disagreement { fast_solver, simulation },
+    })
+}
+
+pub fn simulate_common_termination(graph: &Graph, starts: &NodeSelector, targets: &NodeSelector, step_budget: usize, threads: usize) -> Result<Option<usize>> {
+    let target_nodes: HashSet<&str> = graph.select_nodes(targets).into_iter().collect();
+    let start_nodes = graph.select_nodes(starts);
+    if start_nodes.is_empty() {
+        return Ok(None);
+    }
+
+    let group_size = start_nodes.len().div_ceil(threads.clamp(1, start_nodes.len()));
+    let mut ghost_groups: Vec<Vec<&str>> = start_nodes.chunks(group_size).map(<[&str]>::to_vec).collect();
+    let mut steps = 0;
+
+    while steps < step_budget {
+        let chunk_length = SIMULATION_CHUNK_LENGTH.min(step_budget - steps);
+        let group_hits = thread::scope(|scope| {
+            let handles: Vec<_> = ghost_groups.iter_mut()
+                .map(|group| {
+                    let target_nodes = &target_nodes;
+                    scope.spawn(move || advance_ghost_group(graph, group, steps, chunk_length, target_nodes))
+                })
+                .collect();
+
+            handles.into_iter()
+                .map(|handle| handle.join().expect("Simulation thread panicked"))
+                .collect::<Result<Vec<Vec<bool>>>>()
+        })?;
+
+        if let Some(offset) = (0..chunk_length).find(|&offset| group_hits.iter().all(|hits| hits[offset])) {
+            return Ok(Some(steps + offset + 1));
+        }
+        steps += chunk_length;
+    }
+
+    Ok(None)
+}
+
+fn advance_ghost_group<'a>(graph: &'a Graph, group: &mut [&'a str], first_step: usize, chunk_length: usize, target_nodes: &HashSet<&str>) -> Result<Vec<bool>> {
+    let instructions: Vec<char> = graph.waypoint_instructions().chars().collect();
+    let mut hits = Vec::with_capacity(chunk_length);
+
+    for step in first_step..first_step + chunk_length {
+        let instruction = instructions[step % instructions.len()];
+        for position in group.iter_mut() {
+            *position = graph.get_next_node(position, instruction)?.name();
+        }
+        hits.push(group.iter().all(|position| target_nodes.contains(position)));
+    }
+
+    Ok(hits)
+}
+
+pub fn generate_network(node_count: usize, ghost_count: usize, instruction_length: usize, seed: u64) -> String {
+    let mut state = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+    let mut next_random = move |bound: usize| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        (state % bound as u64) as usize
+    };
+
+    let names: Vec<String> = (0..node_count)
+        .map(|index| {
+            let suffix = if index < ghost_count {
+                'A'
+            } else if next_random(3) == 0 {
+                'Z'
+            } else {
+                'X'
+            };
+            format!("{:02}{}", index, suffix)
+        })
+        .collect();
+
+    let instructions: String = (0..instruction_length)
+        .map(|_| if next_random(2) == 0 { 'L' } else { 'R' })
+        .collect();
+
+    let mut network = format!("{}\n\n", instructions);
+    for name in &names {
+        let left = &names[next_random(node_count)];
+        let right = &names[next_random(node_count)];
+        network.push_str(&format!("{} = ({}, {})\n", name, left, right));
+    }
+
+    network
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use super::*;
+    use crate::utils::input_output::read_file;
+
+    #[rstest]
+    #[case("resources/input_day_8_test_c.txt", 1, Some(6))]
+    #[case("resources/input_day_8_test_c.txt", 2, Some(6))]
+    #[case("resources/input_day_8_test_d.txt", 2, Some(7))]
+    #[case("resources/input_day_8_test_e.txt", 2, None)]
+    fn test_simulate_common_termination(#[case] input_file: &str, #[case] threads: usize, #[case] expected_steps: Option<usize>) {
+        let graph = Graph::new(&read_file(input_file).unwrap()).unwrap();
+        let steps = simulate_common_termination(&graph, &NodeSelector::pattern("*A"), &NodeSelector::pattern("*Z"), 1000, threads).unwrap();
+        assert_eq!(steps, expected_steps);
+    }
+
+    #[test]
+    fn test_cross_check_test_inputs() {
+        let graph = Graph::new(&read_file("resources/input_day_8_test_d.txt").unwrap()).unwrap();
+        let result = cross_check(&graph, &NodeSelector::pattern("*A"), &NodeSelector::pattern("*Z"), 1000, 2).unwrap();
+        assert_eq!(result, CrossCheck::Agreement(7));
+
+        let graph = Graph::new(&read_file("resources/input_day_8_test_e.txt").unwrap()).unwrap();
+        let result = cross_check(&graph, &NodeSelector::pattern("*A"), &NodeSelector::pattern("*Z"), 1000, 2).unwrap();
+        assert_eq!(result, CrossCheck::Inconclusive { fast_solver: None, step_budget: 1000 });
+    }
+
+    #[test]
+    fn test_cross_check_generated_networks() {
+        let mut agreements = 0;
+
+        for seed in 0..100 {
+            let graph = Graph::new(&generate_network(12, 3, 5, seed)).unwrap();
+            let result = cross_check(&graph, &NodeSelector::pattern("*A"), &NodeSelector::pattern("*Z"), 2_000, 3).unwrap();
+
+            match result {
+                CrossCheck::Disagreement { .. } => panic!("Seed {} disagrees: {:?}", seed, result),
+                CrossCheck::Agreement(_) => agreements += 1,
+                CrossCheck::Inconclusive { .. } => {}
+            }
+        }
+
+        assert!(agreements > 0);
+    }
+}
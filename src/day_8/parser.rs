@@ -1,20 +1,21 @@
 use crate::day_8::node::Node;
 use crate::day_8::errors::{Day8ParsingError};
+use crate::day_8::instruction_alphabet::InstructionAlphabet;
 
-pub fn parse_waypoint_instructions(input: &str) -> Result<String, Day8ParsingError> {
+pub fn parse_waypoint_instructions(input: &str, alphabet: &InstructionAlphabet) -> Result<String, Day8ParsingError> {
     let first_line = input.lines()
         .next()
         .map(str::trim)
         .ok_or(Day8ParsingError::EmptyInput)?
         .to_string();
 
-    validate_waypoint_instructions(&first_line)?;
+    validate_waypoint_instructions(&first_line, alphabet)?;
 
     Ok(first_line)
 }
 
-fn validate_waypoint_instructions(instruction: &str) -> Result<(), Day8ParsingError> {
-    if instruction.chars().all(|c| c == 'R' || c == 'L') {
+fn validate_waypoint_instructions(instruction: &str, alphabet: &InstructionAlphabet) -> Result<(), Day8ParsingError> {
+    if !instruction.is_empty() && instruction.chars().all(|c| alphabet.contains(c)) {
         Ok(())
     } else {
         Err(Day8ParsingError::InvalidWaypointInstruction)
@@ -36,7 +37,7 @@ pub fn parse_nodes(input: &str) -> Result<Vec<Node>, Day8ParsingError> {
                     .collect();
             validate_node_connections(&connections)?;
 
-            Ok(Node::new(name, connections[0], connections[1]))
+            Ok(Node::new(name, &connections))
         })
         .collect()
 }
@@ -50,7 +51,7 @@ fn validate_node_definition(parts: &[&str]) -> Result<(), Day8ParsingError> {
 }
 
 fn validate_node_connections(connections: &[&str]) -> Result<(), Day8ParsingError> {
-    if connections.len() != 2 {
+    if connections.iter().any(|connection| connection.is_empty()) {
         Err(Day8ParsingError::InvalidConnectionCount)
     } else {
         Ok(())
@@ -71,7 +72,14 @@ mod tests {
     #[test]
     fn test_parse_waypoint_instructions() {
         let content = read_file("resources/input_day_8_test_a.txt").unwrap();
-        assert_eq!(parse_waypoint_instructions(&content).unwrap(), "RL");
+        assert_eq!(parse_waypoint_instructions(&content, &InstructionAlphabet::default()).unwrap(), "RL");
+    }
+
+    #[test]
+    fn test_parse_waypoint_instructions_with_alphabet() {
+        let content = read_file("resources/input_day_8_test_g.txt").unwrap();
+        assert!(parse_waypoint_instructions(&content, &InstructionAlphabet::default()).is_err());
+        assert_eq!(parse_waypoint_instructions(&content, &InstructionAlphabet::new("LMR")).unwrap(), "MRL");
     }
 
     #[test]
@@ -79,10 +87,23 @@ mod tests {
         let file_content = read_file("resources/input_day_8_test_a.txt").unwrap();
         let nodes = parse_nodes(&file_content).unwrap();
 
-        let expected_node = Node::new("AAA", "BBB", "CCC");
-        let unexpected_node = Node::new("AAA","BBB", "KGB");
+        let expected_node = Node::new("AAA", &["BBB", "CCC"]);
+        let unexpected_node = Node::new("AAA", &["BBB", "KGB"]);
 
         assert!(nodes.contains(&expected_node));
-        assert!(!nodes.iter().any(|node| *node == unexpected_node));
+        assert!(!nodes.contains(&unexpected_node));
+    }
+
+    #[test]
+    fn test_parse_n_ary_nodes() {
+        let file_content = read_file("resources/input_day_8_test_g.txt").unwrap();
+        let nodes = parse_nodes(&file_content).unwrap();
+
+        assert!(nodes.contains(&Node::new("AAA", &["BBB", "CCC", "DDD"])));
+    }
+
+    #[test]
+    fn test_parse_nodes_with_empty_connection() {
+        assert!(parse_nodes("LR\n\nAAA = (BBB, )").is_err());
     }
 }
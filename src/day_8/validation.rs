@@ -0,0 +1,166 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use crate::day_8::node::Node;
+use crate::day_8::node_selector::NodeSelector;
+
+#[derive(Debug, Default, PartialEq)]
+pub struct ValidationReport {
+    pub duplicate_nodes: Vec<String>,
+    pub undefined_references: Vec<(String, String)>,
+    pub missing_start_nodes: bool,
+    pub missing_target_nodes: bool,
+    pub unreachable_nodes: Vec<String>,
+    pub self_loop_only_nodes: Vec<String>,
+}
+
+impl ValidationReport {
+    pub fn new(nodes: &[Node], starts: &NodeSelector, targets: &NodeSelector) -> Self {
+        let mut definitions: HashMap<&str, &Node> = HashMap::new();
+        let mut duplicate_nodes = HashSet::new();
+
+        for node in nodes {
+            if definitions.insert(node.name(), node).is_some() {
+                duplicate_nodes.insert(node.name().to_string());
+            }
+        }
+
+        let mut undefined_references: Vec<(String, String)> = definitions.values()
+            .flat_map(|node| node.successors().iter()
+                .filter(|neighbour| !definitions.contains_key(neighbour.as_str()))
+                .map(|neighbour| (node.name().to_string(), neighbour.to_string())))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        undefined_references.sort();
+
+        let start_nodes: Vec<&str> = definitions.keys().copied().filter(|name| starts.matches(name)).collect();
+        let missing_start_nodes = start_nodes.is_empty();
+        let missing_target_nodes = !definitions.keys().any(|name| targets.matches(name));
+
+        let mut unreachable_nodes = Vec::new();
+        if !missing_start_nodes {
+            let reachable_nodes = find_reachable_nodes(&definitions, &start_nodes);
+            unreachable_nodes = definitions.keys()
+                .filter(|name| !reachable_nodes.contains(*name))
+                .map(|name| name.to_string())
+                .collect();
+        }
+        unreachable_nodes.sort();
+
+        let mut self_loop_only_nodes: Vec<String> = definitions.values()
+            .filter(|node| node.successors().iter().all(|successor| successor == node.name()))
+            .map(|node| node.name().to_string())
+            .collect();
+        self_loop_only_nodes.sort();
+
+        let mut duplicate_nodes: Vec<String> = duplicate_nodes.into_iter().collect();
+        duplicate_nodes.sort();
+
+        ValidationReport {
+            duplicate_nodes,
+            undefined_references,
+            missing_start_nodes,
+            missing_target_nodes,
+            unreachable_nodes,
+            self_loop_only_nodes,
+        }
+    }
+
+    pub fn has_errors(&self) -> bool {
+        !self.duplicate_nodes.is_empty()
+            || !self.undefined_references.is_empty()
+            || self.missing_start_nodes
+            || self.missing_target_nodes
+    }
+
+    pub fn has_warnings(&self) -> bool {
+        !self.unreachable_nodes.is_empty() || !self.self_loop_only_nodes.is_empty()
+    }
+}
+
+fn find_reachable_nodes<'a>(definitions: &HashMap<&'a str, &'a Node>, start_nodes: &[&'a str]) -> HashSet<&'a str> {
+    let mut reachable_nodes: HashSet<&str> = start_nodes.iter().copied().collect();
+    let mut to_visit: VecDeque<&str> = start_nodes.iter().copied().collect();
+
+    while let Some(name) = to_visit.pop_front() {
+        if let Some(node) = definitions.get(name) {
+            for neighbour in node.successors() {
+                if let Some((&neighbour, _)) = definitions.get_key_value(neighbour.as_str()) {
+                    if reachable_nodes.insert(neighbour) {
+                        to_visit.push_back(neighbour);
+                    }
+                }
+            }
+        }
+    }
+
+    reachable_nodes
+}
+
+impl std::fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if !self.duplicate_nodes.is_empty() {
+            writeln!(f, "Duplicate node definitions: {}", self.duplicate_nodes.join(", "))?;
+        }
+        for (node, neighbour) in &self.undefined_references {
+            writeln!(f, "Node '{}' references undefined node '{}'", node, neighbour)?;
+        }
+        if self.missing_start_nodes {
+            writeln!(f, "No start nodes found")?;
+        }
+        if self.missing_target_nodes {
+            writeln!(f, "No target nodes found")?;
+        }
+        if !self.unreachable_nodes.is_empty() {
+            writeln!(f, "Nodes unreachable from the start nodes: {}", self.unreachable_nodes.join(", "))?;
+        }
+        if !self.self_loop_only_nodes.is_empty() {
+            writeln!(f, "Nodes that only loop to themselves: {}", self.self_loop_only_nodes.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_8::parser::parse_nodes;
+    use crate::utils::input_output::read_file;
+
+    #[test]
+    fn test_validation_report_for_valid_graph() {
+        let nodes = parse_nodes(&read_file("resources/input_day_8_test_a.txt").unwrap()).unwrap();
+        let report = ValidationReport::new(&nodes, &NodeSelector::names(&["AAA"]), &NodeSelector::names(&["ZZZ"]));
+
+        assert!(!report.has_errors());
+        assert!(report.unreachable_nodes.is_empty());
+        assert_eq!(report.self_loop_only_nodes, vec!["DDD", "EEE", "GGG", "ZZZ"]);
+    }
+
+    #[test]
+    fn test_validation_report_for_invalid_graph() {
+        let nodes = parse_nodes(&read_file("resources/input_day_8_test_f.txt").unwrap()).unwrap();
+        let report = ValidationReport::new(&nodes, &NodeSelector::names(&["AAA"]), &NodeSelector::names(&["ZZZ"]));
+
+        let expected_report = ValidationReport {
+            duplicate_nodes: vec!["BBB".to_string()],
+            undefined_references: vec![("AAA".to_string(), "QQQ".to_string())],
+            missing_start_nodes: false,
+            missing_target_nodes: false,
+            unreachable_nodes: vec!["XXX".to_string()],
+            self_loop_only_nodes: vec!["ZZZ".to_string()],
+        };
+
+        assert_eq!(report, expected_report);
+        assert!(report.has_errors());
+    }
+
+    #[test]
+    fn test_validation_report_for_missing_start_and_target_nodes() {
+        let nodes = parse_nodes(&read_file("resources/input_day_8_test_a.txt").unwrap()).unwrap();
+        let report = ValidationReport::new(&nodes, &NodeSelector::pattern("*X"), &NodeSelector::pattern("*Y"));
+
+        assert!(report.missing_start_nodes);
+        assert!(report.missing_target_nodes);
+        assert!(report.unreachable_nodes.is_empty());
+    }
+}
@@ -0,0 +1,104 @@
+use crate::day_8::graph::Graph;
+
+pub struct Walk<'a> {
+    graph: &'a Graph,
+    instructions: Vec<char>,
+    current_node: Option<&'a str>,
+    step: usize,
+}
+
+impl<'a> Walk<'a> {
+    pub fn new(graph: &'a Graph, start: &'a str) -> Self {
+        Walk {
+            graph,
+            instructions: graph.waypoint_instructions().chars().collect(),
+            current_node: Some(start),
+            step: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for Walk<'a> {
+    type Item = (usize, usize, char, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.current_node?;
+        let instruction_index = self.step % self.instructions.len();
+        let instruction = self.instructions[instruction_index];
+        let item = (self.step, instruction_index, instruction, node);
+
+        self.current_node = self.graph.get_next_node(node, instruction).ok().map(|next_node| next_node.name());
+        self.step += 1;
+
+        Some(item)
+    }
+}
+
+pub struct LockstepWalk<'a> {
+    walkers: Vec<Walk<'a>>,
+}
+
+impl<'a> LockstepWalk<'a> {
+    pub fn new(walkers: Vec<Walk<'a>>) -> Self {
+        LockstepWalk { walkers }
+    }
+}
+
+impl<'a> Iterator for LockstepWalk<'a> {
+    type Item = (usize, usize, char, Vec<&'a str>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut positions = Vec::with_capacity(self.walkers.len());
+        let mut current_step = None;
+
+        for walker in &mut self.walkers {
+            let (step, instruction_index, instruction, node) = walker.next()?;
+            current_step = Some((step, instruction_index, instruction));
+            positions.push(node);
+        }
+
+        let (step, instruction_index, instruction) = current_step?;
+        Some((step, instruction_index, instruction, positions))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day_8::node_selector::NodeSelector;
+    use crate::utils::input_output::read_file;
+    use super::*;
+
+    #[test]
+    fn test_walk() {
+        let graph = Graph::new(&read_file("resources/input_day_8_test_b.txt").unwrap()).unwrap();
+        let trace: Vec<(usize, usize, char, &str)> = graph.walk("AAA").unwrap().take(7).collect();
+
+        let expected_trace = vec![
+            (0, 0, 'L', "AAA"),
+            (1, 1, 'L', "BBB"),
+            (2, 2, 'R', "AAA"),
+            (3, 0, 'L', "BBB"),
+            (4, 1, 'L', "AAA"),
+            (5, 2, 'R', "BBB"),
+            (6, 0, 'L', "ZZZ"),
+        ];
+        assert_eq!(trace, expected_trace);
+    }
+
+    #[test]
+    fn test_walk_from_unknown_node() {
+        let graph = Graph::new(&read_file("resources/input_day_8_test_b.txt").unwrap()).unwrap();
+        assert!(graph.walk("XXX").is_err());
+    }
+
+    #[test]
+    fn test_walk_lockstep() {
+        let graph = Graph::new(&read_file("resources/input_day_8_test_c.txt").unwrap()).unwrap();
+        let (step, _, _, positions) = graph.walk_lockstep(&NodeSelector::pattern("*A")).unwrap()
+            .find(|(_, _, _, positions)| positions.iter().all(|node| node.ends_with('Z')))
+            .unwrap();
+
+        assert_eq!(step, 6);
+        assert_eq!(positions, vec!["11Z", "22Z"]);
+    }
+}
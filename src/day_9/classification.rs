@@ -0,0 +1,111 @@
+use std::fmt;
+use num::{BigRational, Zero};
+use crate::day_9::errors::SequenceError;
+use crate::day_9::number::{SequenceNumber, ToRational};
+use crate::day_9::sequence_analysis::calculate_complete_difference_sequences;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SequenceClass {
+    Polynomial { degree: usize },
+    InsufficientData { known_points: usize },
+    NonPolynomial(NonPolynomialKind),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum NonPolynomialKind {
+    Geometric { ratio: BigRational },
+    FibonacciLike,
+}
+
+impl SequenceClass {
+    pub fn is_polynomial(&self) -> bool {
+        matches!(self, SequenceClass::Polynomial { .. })
+    }
+}
+
+pub fn classify_sequence<T: SequenceNumber + ToRational>(sequence: &[T]) -> Result<SequenceClass, SequenceError> {
+    let difference_sequences = calculate_complete_difference_sequences(sequence)?;
+    Ok(classify_difference_sequences(&difference_sequences))
+}
+
+pub fn classify_difference_sequences<T: SequenceNumber + ToRational>(difference_sequences: &[Vec<T>]) -> SequenceClass {
+    let zero_row_is_known = difference_sequences.last().is_some_and(|row| !row.is_empty());
+    if zero_row_is_known {
+        return SequenceClass::Polynomial { degree: difference_sequences.len().saturating_sub(2) };
+    }
+
+    let sequence: Vec<BigRational> = difference_sequences.first()
+        .map(|sequence| sequence.iter().map(ToRational::to_rational).collect())
+        .unwrap_or_default();
+
+    if let Some(ratio) = common_ratio(&sequence) {
+        SequenceClass::NonPolynomial(NonPolynomialKind::Geometric { ratio })
+    } else if is_fibonacci_like(&sequence) {
+        SequenceClass::NonPolynomial(NonPolynomialKind::FibonacciLike)
+    } else {
+        SequenceClass::InsufficientData { known_points: sequence.len() }
+    }
+}
+
+fn common_ratio(sequence: &[BigRational]) -> Option<BigRational> {
+    if sequence.len() < 3 || sequence.iter().any(Zero::is_zero) {
+        return None;
+    }
+
+    let ratio = &sequence[1] / &sequence[0];
+    if sequence.windows(2).all(|window| &window[1] / &window[0] == ratio) {
+        Some(ratio)
+    } else {
+        None
+    }
+}
+
+fn is_fibonacci_like(sequence: &[BigRational]) -> bool {
+    sequence.len() >= 3 && sequence.windows(3).all(|window| &window[0] + &window[1] == window[2])
+}
+
+impl fmt::Display for SequenceClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SequenceClass::Polynomial { degree } => write!(f, "polynomial of degree {}", degree),
+            SequenceClass::InsufficientData { known_points } => write!(f, "insufficient data ({} points never reach a zero difference row)", known_points),
+            SequenceClass::NonPolynomial(NonPolynomialKind::Geometric { ratio }) => write!(f, "non-polynomial, geometric with ratio {}", ratio),
+            SequenceClass::NonPolynomial(NonPolynomialKind::FibonacciLike) => write!(f, "non-polynomial, Fibonacci-like"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use num::BigInt;
+    use super::*;
+    use crate::day_9::parser::parse_sequences_from_file;
+
+    #[rstest]
+    #[case(0, SequenceClass::Polynomial { degree: 1 })]
+    #[case(1, SequenceClass::NonPolynomial(NonPolynomialKind::Geometric { ratio: BigRational::from_integer(BigInt::from(2)) }))]
+    #[case(2, SequenceClass::NonPolynomial(NonPolynomialKind::FibonacciLike))]
+    #[case(3, SequenceClass::InsufficientData { known_points: 2 })]
+    #[case(4, SequenceClass::InsufficientData { known_points: 8 })]
+    fn test_classify_sequence(#[case] test_sequence: usize, #[case] expected_class: SequenceClass) {
+        let sequences = parse_sequences_from_file::<i64>("resources/input_day_9_test_b.txt").unwrap();
+        assert_eq!(classify_sequence(&sequences[test_sequence]).unwrap(), expected_class);
+    }
+
+    #[rstest]
+    #[case(vec![7, 7], SequenceClass::Polynomial { degree: 0 })]
+    #[case(vec![0, 0, 0], SequenceClass::Polynomial { degree: 0 })]
+    #[case(vec![1, 3, 6, 10, 15, 21], SequenceClass::Polynomial { degree: 2 })]
+    #[case(vec![], SequenceClass::InsufficientData { known_points: 0 })]
+    #[case(vec![5], SequenceClass::InsufficientData { known_points: 1 })]
+    fn test_classify_edge_cases(#[case] sequence: Vec<i32>, #[case] expected_class: SequenceClass) {
+        assert_eq!(classify_sequence(&sequence).unwrap(), expected_class);
+    }
+
+    #[test]
+    fn test_display() {
+        let class = SequenceClass::NonPolynomial(NonPolynomialKind::Geometric { ratio: BigRational::new(1.into(), 2.into()) });
+        assert_eq!(class.to_string(), "non-polynomial, geometric with ratio 1/2");
+    }
+}
@@ -0,0 +1,23 @@
+use num::BigRational;
+use crate::day_9::classification::SequenceClass;
+
+#[derive(Debug)]
+pub enum SequenceError {
+    Overflow,
+    NotPolynomial { sequence_index: usize, classification: SequenceClass },
+    UndeterminedGap { sequence_index: usize, missing_positions: Vec<usize>, known_points: usize },
+    UnrepresentableReading { sequence_index: usize, position: usize, value: BigRational },
+}
+
+impl std::fmt::Display for SequenceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SequenceError::Overflow => write!(f, "Arithmetic overflow while analysing sequence"),
+            SequenceError::NotPolynomial { sequence_index, classification } => write!(f, "Sequence {} cannot be extrapolated, it is {}", sequence_index + 1, classification),
+            SequenceError::UndeterminedGap { sequence_index, missing_positions, known_points } => write!(f, "Missing readings at positions {:?} of sequence {} cannot be determined uniquely from {} known points", missing_positions, sequence_index + 1, known_points),
+            SequenceError::UnrepresentableReading { sequence_index, position, value } => write!(f, "Reconstructed reading {} at position {} of sequence {} is not representable", value, position, sequence_index + 1),
+        }
+    }
+}
+
+impl std::error::Error for SequenceError {}
@@ -0,0 +1,134 @@
+use num::{BigInt, BigRational, One, Zero};
+use crate::day_9::errors::SequenceError;
+use crate::day_9::number::{FromRational, SequenceNumber, ToRational};
+
+pub fn fill_missing_readings<T>(sequences: &[Vec<Option<T>>]) -> Result<Vec<Vec<T>>, SequenceError>
+    where
+        T: SequenceNumber + ToRational + FromRational
+{
+    sequences
+        .iter()
+        .enumerate()
+        .map(|(sequence_index, readings)| fill_sequence(sequence_index, readings))
+        .collect()
+}
+
+pub fn fill_sequence<T>(sequence_index: usize, readings: &[Option<T>]) -> Result<Vec<T>, SequenceError>
+    where
+        T: SequenceNumber + ToRational + FromRational
+{
+    let missing_positions: Vec<usize> = readings.iter()
+        .enumerate()
+        .filter(|(_, reading)| reading.is_none())
+        .map(|(position, _)| position)
+        .collect();
+
+    if missing_positions.is_empty() {
+        return Ok(readings.iter().flatten().cloned().collect());
+    }
+
+    let known_points: Vec<(BigRational, BigRational)> = readings.iter()
+        .enumerate()
+        .filter_map(|(position, reading)| reading.as_ref().map(|value| (position_to_rational(position), value.to_rational())))
+        .collect();
+
+    let interpolation_points = minimal_interpolation_points(&known_points)
+        .ok_or_else(|| SequenceError::UndeterminedGap {
+            sequence_index,
+            missing_positions: missing_positions.clone(),
+            known_points: known_points.len(),
+        })?;
+
+    readings.iter()
+        .enumerate()
+        .map(|(position, reading)| match reading {
+            Some(value) => Ok(value.clone()),
+            None => {
+                let value = lagrange_interpolate(interpolation_points, &position_to_rational(position));
+                T::from_rational(&value).ok_or(SequenceError::UnrepresentableReading { sequence_index, position, value })
+            }
+        })
+        .collect()
+}
+
+fn minimal_interpolation_points(known_points: &[(BigRational, BigRational)]) -> Option<&[(BigRational, BigRational)]> {
+    (1..known_points.len())
+        .map(|point_count| known_points.split_at(point_count))
+        .find(|(interpolation_points, verification_points)| {
+            verification_points.iter().all(|(x, y)| lagrange_interpolate(interpolation_points, x) == *y)
+        })
+        .map(|(interpolation_points, _)| interpolation_points)
+}
+
+fn lagrange_interpolate(points: &[(BigRational, BigRational)], x: &BigRational) -> BigRational {
+    let mut value = BigRational::zero();
+
+    for (i, (x_i, y_i)) in points.iter().enumerate() {
+        let mut basis = BigRational::one();
+        for (j, (x_j, _)) in points.iter().enumerate() {
+            if i != j {
+                basis = basis * (x - x_j) / (x_i - x_j);
+            }
+        }
+        value += y_i * basis;
+    }
+
+    value
+}
+
+fn position_to_rational(position: usize) -> BigRational {
+    BigRational::from_integer(BigInt::from(position))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_9::parser::parse_readings_from_file;
+
+    #[test]
+    fn test_fill_missing_readings() {
+        let readings = parse_readings_from_file::<i64>("resources/input_day_9_test_c.txt").unwrap();
+
+        assert_eq!(fill_missing_readings(&readings[0..2]).unwrap(), vec![vec![0, 3, 6, 9, 12, 15], vec![1, 3, 6, 10, 15, 21]]);
+        assert_eq!(fill_missing_readings(&readings[3..4]).unwrap(), vec![vec![0, 2, 4, 6, 8]]);
+    }
+
+    #[test]
+    fn test_undetermined_gap_is_reported() {
+        let readings = parse_readings_from_file::<i64>("resources/input_day_9_test_c.txt").unwrap();
+
+        match fill_missing_readings(&readings) {
+            Err(SequenceError::UndeterminedGap { sequence_index, missing_positions, known_points }) => {
+                assert_eq!(sequence_index, 2);
+                assert_eq!(missing_positions, vec![3, 4]);
+                assert_eq!(known_points, 4);
+            }
+            result => panic!("Expected undetermined gap, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_complete_and_constant_sequences() {
+        assert_eq!(fill_missing_readings(&[vec![Some(10), Some(13), Some(16)]]).unwrap(), vec![vec![10, 13, 16]]);
+        assert_eq!(fill_missing_readings(&[vec![None, Some(5), Some(5)]]).unwrap(), vec![vec![5, 5, 5]]);
+        assert!(fill_missing_readings::<i32>(&[vec![Some(0), None]]).is_err());
+        assert!(fill_missing_readings::<i32>(&[vec![None, None]]).is_err());
+    }
+
+    #[test]
+    fn test_non_integral_reading_is_reported() {
+        let readings = vec![vec![Some(0), None, Some(1), None, Some(2)]];
+
+        match fill_missing_readings::<i32>(&readings) {
+            Err(SequenceError::UnrepresentableReading { position, value, .. }) => {
+                assert_eq!(position, 1);
+                assert_eq!(value, BigRational::new(1.into(), 2.into()));
+            }
+            result => panic!("Expected unrepresentable reading, got {:?}", result),
+        }
+
+        let rational_readings: Vec<Option<BigRational>> = readings[0].iter().map(|reading| reading.map(|value: i32| value.to_rational())).collect();
+        let filled = fill_missing_readings(&[rational_readings]).unwrap();
+        assert_eq!(filled[0][3], BigRational::new(3.into(), 2.into()));
+    }
+}
@@ -1,17 +1,25 @@
-use crate::day_9::parser::parse_sequences_from_file;
-use crate::day_9::sequence_analysis::{analyze_and_predict_next_numbers, analyze_and_predict_preceding_numbers, sum_of_predictions};
+use crate::day_9::missing_readings::fill_missing_readings;
+use crate::day_9::parser::parse_readings_from_file;
+use crate::day_9::sequence_analysis::{analyze_and_predict_neighbours, sum_of_predictions};
 use anyhow::{Result};
 
 mod parser;
 mod errors;
 mod sequence_analysis;
+mod number;
+mod classification;
+mod missing_readings;
+#[allow(dead_code)]
+mod polynomial;
+#[allow(dead_code)]
+mod streaming;
 
-pub fn solve_day_9() -> Result<(i32,i32)> {
-    let sequences = parse_sequences_from_file("resources/input_day_9.txt")?;
-    let extrapolated_next_numbers = analyze_and_predict_next_numbers(&sequences);
-    let extrapolated_preceding_numbers = analyze_and_predict_preceding_numbers(&sequences);
+pub fn solve_day_9() -> Result<(i64,i64)> {
+    let readings = parse_readings_from_file::<i64>("resources/input_day_9.txt")?;
+    let sequences = fill_missing_readings(&readings)?;
+    let (extrapolated_next_numbers, extrapolated_preceding_numbers): (Vec<i64>, Vec<i64>) = analyze_and_predict_neighbours(&sequences)?.into_iter().unzip();
 
-    let part_1_sum = sum_of_predictions(&extrapolated_next_numbers);
-    let part_2_sum = sum_of_predictions(&extrapolated_preceding_numbers);
+    let part_1_sum = sum_of_predictions(&extrapolated_next_numbers)?;
+    let part_2_sum = sum_of_predictions(&extrapolated_preceding_numbers)?;
     Ok((part_1_sum, part_2_sum))
 }
@@ -0,0 +1,79 @@
+use std::fmt::Debug;
+use num::{BigInt, BigRational, CheckedAdd, CheckedSub, Zero};
+use crate::day_9::errors::SequenceError;
+
+pub trait SequenceNumber: Clone + PartialEq + Debug + Zero + CheckedAdd + CheckedSub {}
+
+impl<T: Clone + PartialEq + Debug + Zero + CheckedAdd + CheckedSub> SequenceNumber for T {}
+
+pub fn checked_sum<T: SequenceNumber>(a: &T, b: &T) -> Result<T, SequenceError> {
+    a.checked_add(b).ok_or(SequenceError::Overflow)
+}
+
+pub fn checked_difference<T: SequenceNumber>(a: &T, b: &T) -> Result<T, SequenceError> {
+    a.checked_sub(b).ok_or(SequenceError::Overflow)
+}
+
+pub trait ToRational {
+    fn to_rational(&self) -> BigRational;
+}
+
+macro_rules! impl_to_rational_for_integers {
+    ($($integer:ty),*) => {
+        $(impl ToRational for $integer {
+            fn to_rational(&self) -> BigRational {
+                BigRational::from_integer(BigInt::from(*self))
+            }
+        })*
+    };
+}
+
+impl_to_rational_for_integers!(i8, i16, i32, i64, i128);
+
+pub trait FromRational: Sized {
+    fn from_rational(value: &BigRational) -> Option<Self>;
+}
+
+macro_rules! impl_from_rational_for_integers {
+    ($($integer:ty),*) => {
+        $(impl FromRational for $integer {
+            fn from_rational(value: &BigRational) -> Option<Self> {
+                if value.is_integer() {
+                    <$integer>::try_from(value.to_integer()).ok()
+                } else {
+                    None
+                }
+            }
+        })*
+    };
+}
+
+impl_from_rational_for_integers!(i8, i16, i32, i64, i128);
+
+impl FromRational for BigInt {
+    fn from_rational(value: &BigRational) -> Option<Self> {
+        if value.is_integer() {
+            Some(value.to_integer())
+        } else {
+            None
+        }
+    }
+}
+
+impl FromRational for BigRational {
+    fn from_rational(value: &BigRational) -> Option<Self> {
+        Some(value.clone())
+    }
+}
+
+impl ToRational for BigInt {
+    fn to_rational(&self) -> BigRational {
+        BigRational::from_integer(self.clone())
+    }
+}
+
+impl ToRational for BigRational {
+    fn to_rational(&self) -> BigRational {
+        self.clone()
+    }
+}
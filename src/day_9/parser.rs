@@ -2,10 +2,55 @@ use anyhow::{Result, Context};
 use std::fs::File;
 use std::io::{self, BufRead};
 use std::path::Path;
+use std::str::FromStr;
 
-pub fn parse_sequences_from_file(file_path: &str) -> Result<Vec<Vec<i32>>> {
+const MISSING_READING_PLACEHOLDERS: [&str; 2] = ["?", "_"];
+
+#[allow(dead_code)]
+pub fn parse_sequences_from_file<T>(file_path: &str) -> Result<Vec<Vec<T>>>
+    where
+        T: FromStr,
+        T::Err: std::error::Error + Send + Sync + 'static
+{
+    parse_lines(file_path, |token| token.parse::<T>().context("Failed to parse number"))
+}
+
+pub fn parse_readings_from_file<T>(file_path: &str) -> Result<Vec<Vec<Option<T>>>>
+    where
+        T: FromStr,
+        T::Err: std::error::Error + Send + Sync + 'static
+{
+    parse_lines(file_path, parse_reading)
+}
+
+pub fn parse_readings_line<T>(line: &str) -> Result<Vec<Option<T>>>
+    where
+        T: FromStr,
+        T::Err: std::error::Error + Send + Sync + 'static
+{
+    line.split_whitespace()
+        .map(parse_reading)
+        .collect()
+}
+
+fn parse_reading<T>(token: &str) -> Result<Option<T>>
+    where
+        T: FromStr,
+        T::Err: std::error::Error + Send + Sync + 'static
+{
+    if MISSING_READING_PLACEHOLDERS.contains(&token) {
+        Ok(None)
+    } else {
+        token.parse::<T>().map(Some).context("Failed to parse reading")
+    }
+}
+
+fn parse_lines<V, F>(file_path: &str, parse_token: F) -> Result<Vec<Vec<V>>>
+    where
+        F: Fn(&str) -> Result<V>
+{
     let path = Path::new(file_path);
-    let file = File::open(&path).context("Failed to open file")?;
+    let file = File::open(path).context("Failed to open file")?;
     let reader = io::BufReader::new(file);
 
     reader.lines()
@@ -13,7 +58,7 @@ pub fn parse_sequences_from_file(file_path: &str) -> Result<Vec<Vec<i32>>> {
             line.context("Failed to read line")
                 .and_then(|l| {
                     l.split_whitespace()
-                        .map(|num| num.parse::<i32>().context("Failed to parse number"))
+                        .map(&parse_token)
                         .collect()
                 })
         })
@@ -33,8 +78,20 @@ mod tests{
 
     #[test]
     fn test_parse_sequences_from_file(){
-        let sequences = parse_sequences_from_file("resources/input_day_9_test.txt").unwrap();
+        let sequences = parse_sequences_from_file::<i32>("resources/input_day_9_test.txt").unwrap();
         let expected_sequence = vec![0, 3, 6, 9, 12, 15];
         assert!(sequences.contains(&expected_sequence));
     }
+
+    #[test]
+    fn test_parse_readings_from_file(){
+        let readings = parse_readings_from_file::<i32>("resources/input_day_9_test_c.txt").unwrap();
+        assert_eq!(readings[0], vec![Some(0), Some(3), None, Some(9), Some(12), Some(15)]);
+        assert_eq!(readings[1], vec![Some(1), Some(3), Some(6), None, Some(15), Some(21)]);
+    }
+
+    #[test]
+    fn test_parse_sequences_rejects_placeholders(){
+        assert!(parse_sequences_from_file::<i32>("resources/input_day_9_test_c.txt").is_err());
+    }
 }
@@ -0,0 +1,179 @@
+use std::fmt;
+use num::{BigInt, BigRational, One, Signed, Zero};
+use crate::day_9::errors::SequenceError;
+use crate::day_9::number::{SequenceNumber, ToRational};
+use crate::day_9::sequence_analysis::calculate_complete_difference_sequences;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polynomial {
+    newton_coefficients: Vec<BigRational>,
+    coefficients: Vec<BigRational>,
+}
+
+impl Polynomial {
+    pub fn fit<T: SequenceNumber + ToRational>(sequence: &[T]) -> Result<Polynomial, SequenceError> {
+        let difference_sequences = calculate_complete_difference_sequences(sequence)?;
+        Ok(Polynomial::from_difference_sequences(&difference_sequences))
+    }
+
+    pub fn from_difference_sequences<T: ToRational>(difference_sequences: &[Vec<T>]) -> Polynomial {
+        let mut newton_coefficients: Vec<BigRational> = difference_sequences.iter()
+            .filter_map(|sequence| sequence.first())
+            .map(ToRational::to_rational)
+            .collect();
+
+        while newton_coefficients.last().is_some_and(Zero::is_zero) {
+            newton_coefficients.pop();
+        }
+
+        let coefficients = newton_to_monomial_coefficients(&newton_coefficients);
+        Polynomial { newton_coefficients, coefficients }
+    }
+
+    pub fn degree(&self) -> usize {
+        self.newton_coefficients.len().saturating_sub(1)
+    }
+
+    pub fn coefficients(&self) -> &[BigRational] {
+        &self.coefficients
+    }
+
+    pub fn evaluate(&self, index: &BigInt) -> BigRational {
+        let index = BigRational::from_integer(index.clone());
+        let mut binomial = BigRational::one();
+        let mut value = BigRational::zero();
+
+        for (k, newton_coefficient) in self.newton_coefficients.iter().enumerate() {
+            if k > 0 {
+                binomial = binomial * (&index - rational(k - 1)) / rational(k);
+            }
+            value += newton_coefficient * &binomial;
+        }
+
+        value
+    }
+}
+
+fn rational(value: usize) -> BigRational {
+    BigRational::from_integer(BigInt::from(value))
+}
+
+fn newton_to_monomial_coefficients(newton_coefficients: &[BigRational]) -> Vec<BigRational> {
+    let mut coefficients = vec![BigRational::zero(); newton_coefficients.len()];
+    let mut binomial_coefficients = vec![BigRational::one()];
+
+    for (k, newton_coefficient) in newton_coefficients.iter().enumerate() {
+        if k > 0 {
+            let mut next_binomial_coefficients = vec![BigRational::zero(); binomial_coefficients.len() + 1];
+            for (power, coefficient) in binomial_coefficients.iter().enumerate() {
+                next_binomial_coefficients[power + 1] += coefficient / rational(k);
+                next_binomial_coefficients[power] -= coefficient * rational(k - 1) / rational(k);
+            }
+            binomial_coefficients = next_binomial_coefficients;
+        }
+
+        for (power, coefficient) in binomial_coefficients.iter().enumerate() {
+            coefficients[power] += newton_coefficient * coefficient;
+        }
+    }
+
+    coefficients
+}
+
+impl fmt::Display for Polynomial {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let terms: Vec<(usize, &BigRational)> = self.coefficients.iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, coefficient)| !coefficient.is_zero())
+            .collect();
+
+        if terms.is_empty() {
+            return write!(f, "0");
+        }
+
+        for (position, (power, coefficient)) in terms.into_iter().enumerate() {
+            let magnitude = coefficient.abs();
+            if position == 0 {
+                if coefficient.is_negative() {
+                    write!(f, "-")?;
+                }
+            } else {
+                write!(f, " {} ", if coefficient.is_negative() { '-' } else { '+' })?;
+            }
+
+            if !(magnitude.is_one() && power > 0) {
+                if magnitude.is_integer() {
+                    write!(f, "{}", magnitude)?;
+                } else {
+                    write!(f, "({})", magnitude)?;
+                }
+            }
+
+            match power {
+                0 => {}
+                1 => write!(f, "n")?,
+                _ => write!(f, "n^{}", power)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use super::*;
+    use crate::day_9::parser::parse_sequences_from_file;
+
+    #[rstest]
+    #[case(0, 1, "3n")]
+    #[case(1, 2, "(1/2)n^2 + (3/2)n + 1")]
+    #[case(2, 3, "(1/3)n^3 - n^2 + (11/3)n + 10")]
+    fn test_fit(#[case] test_sequence: usize, #[case] expected_degree: usize, #[case] expected_polynomial: &str) {
+        let sequences = parse_sequences_from_file::<i64>("resources/input_day_9_test.txt").unwrap();
+        let polynomial = Polynomial::fit(&sequences[test_sequence]).unwrap();
+
+        assert_eq!(polynomial.degree(), expected_degree);
+        assert_eq!(polynomial.to_string(), expected_polynomial);
+    }
+
+    #[test]
+    fn test_coefficients() {
+        let polynomial = Polynomial::fit(&[1, 3, 6, 10, 15, 21]).unwrap();
+        let expected_coefficients = vec![
+            BigRational::from_integer(1.into()),
+            BigRational::new(3.into(), 2.into()),
+            BigRational::new(1.into(), 2.into()),
+        ];
+
+        assert_eq!(polynomial.coefficients(), expected_coefficients.as_slice());
+    }
+
+    #[rstest]
+    #[case(6, 68)]
+    #[case(-1, 5)]
+    #[case(0, 10)]
+    fn test_evaluate_matches_extrapolation(#[case] index: i64, #[case] expected: i64) {
+        let polynomial = Polynomial::fit(&[10, 13, 16, 21, 30, 45]).unwrap();
+        assert_eq!(polynomial.evaluate(&BigInt::from(index)), BigRational::from_integer(expected.into()));
+    }
+
+    #[test]
+    fn test_evaluate_at_huge_index() {
+        let polynomial = Polynomial::fit(&[1, 3, 6, 10, 15, 21]).unwrap();
+        let index = BigInt::from(10).pow(30);
+        let expected = (&index + 1) * (&index + 2) / 2;
+
+        assert_eq!(polynomial.evaluate(&index), BigRational::from_integer(expected));
+    }
+
+    #[test]
+    fn test_constant_and_zero_polynomials() {
+        assert_eq!(Polynomial::fit(&[0, 0, 0]).unwrap().to_string(), "0");
+        assert_eq!(Polynomial::fit(&[-4, -4, -4]).unwrap().to_string(), "-4");
+        assert_eq!(Polynomial::fit(&[-4, -4, -4]).unwrap().degree(), 0);
+        assert_eq!(Polynomial::fit(&[5, 4, 3]).unwrap().to_string(), "-n + 5");
+    }
+}
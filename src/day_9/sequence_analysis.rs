@@ -1,67 +1,163 @@
-pub fn sum_of_predictions(predictions: &[i32]) -> i32 {
-    predictions.iter().sum()
+use crate::day_9::errors::SequenceError;
+use crate::day_9::classification::{classify_difference_sequences, classify_sequence};
+use crate::day_9::number::{checked_difference, checked_sum, SequenceNumber, ToRational};
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    Forward,
+    Backward,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, PartialEq)]
+pub struct ExtendedSequence<T> {
+    pub sequence: Vec<T>,
+    pub difference_sequences: Vec<Vec<T>>,
+}
+
+pub fn sum_of_predictions<T: SequenceNumber>(predictions: &[T]) -> Result<T, SequenceError> {
+    predictions.iter().try_fold(T::zero(), |sum, prediction| checked_sum(&sum, prediction))
+}
+
+pub fn analyze_and_predict_neighbours<T: SequenceNumber + ToRational>(sequences: &[Vec<T>]) -> Result<Vec<(T, T)>, SequenceError> {
+    sequences
+        .iter()
+        .enumerate()
+        .map(|(sequence_index, sequence)| predict_neighbours(sequence_index, sequence))
+        .collect()
 }
 
-pub fn analyze_and_predict_next_numbers(sequences: &[Vec<i32>]) -> Vec<i32> {
+pub fn predict_neighbours<T: SequenceNumber + ToRational>(sequence_index: usize, sequence: &[T]) -> Result<(T, T), SequenceError> {
+    let mut row = sequence.to_vec();
+    let mut row_length = row.len();
+    let mut next_number = T::zero();
+    let mut preceding_number = T::zero();
+    let mut subtract_first_number = false;
+
+    while row[..row_length].iter().any(|x| !x.is_zero()) {
+        next_number = checked_sum(&next_number, &row[row_length - 1])?;
+        preceding_number = if subtract_first_number {
+            checked_difference(&preceding_number, &row[0])?
+        } else {
+            checked_sum(&preceding_number, &row[0])?
+        };
+        subtract_first_number = !subtract_first_number;
+
+        for index in 0..row_length - 1 {
+            row[index] = checked_difference(&row[index + 1], &row[index])?;
+        }
+        row_length -= 1;
+    }
+
+    if row_length == 0 {
+        let classification = classify_sequence(sequence)?;
+        return Err(SequenceError::NotPolynomial { sequence_index, classification });
+    }
+
+    Ok((next_number, preceding_number))
+}
+
+#[allow(dead_code)]
+pub fn analyze_and_predict_next_numbers<T: SequenceNumber + ToRational>(sequences: &[Vec<T>]) -> Result<Vec<T>, SequenceError> {
     analyze_and_predict_numbers(sequences, extrapolate_next_number)
 }
 
-pub fn analyze_and_predict_preceding_numbers(sequences: &[Vec<i32>]) -> Vec<i32> {
+#[allow(dead_code)]
+pub fn analyze_and_predict_preceding_numbers<T: SequenceNumber + ToRational>(sequences: &[Vec<T>]) -> Result<Vec<T>, SequenceError> {
     analyze_and_predict_numbers(sequences, extrapolate_preceeding_number)
 }
 
-pub fn analyze_and_predict_numbers<F>(sequences: &[Vec<i32>], extrapolate: F) -> Vec<i32>
+#[allow(dead_code)]
+pub fn analyze_and_predict_numbers<T, F>(sequences: &[Vec<T>], extrapolate: F) -> Result<Vec<T>, SequenceError>
     where
-        F: Fn(&[Vec<i32>]) -> i32
+        T: SequenceNumber + ToRational,
+        F: Fn(&[Vec<T>]) -> Result<T, SequenceError>
 {
     sequences
-        .into_iter()
-        .map(|sequence| {
-            let difference_sequences = calculate_complete_difference_sequences(&sequence);
+        .iter()
+        .enumerate()
+        .map(|(sequence_index, sequence)| {
+            let difference_sequences = calculate_complete_difference_sequences(sequence)?;
+            let classification = classify_difference_sequences(&difference_sequences);
+            if !classification.is_polynomial() {
+                return Err(SequenceError::NotPolynomial { sequence_index, classification });
+            }
             extrapolate(&difference_sequences)
         })
         .collect()
 }
 
-pub fn extrapolate_next_number(sequences: &[Vec<i32>]) -> i32 {
-    let mut next_number = 0;
+pub fn extrapolate_next_number<T: SequenceNumber>(sequences: &[Vec<T>]) -> Result<T, SequenceError> {
+    let mut next_number = T::zero();
     for sequence in sequences.iter().rev() {
-        next_number += sequence.last().copied().unwrap_or_default();
+        next_number = checked_sum(&next_number, &sequence.last().cloned().unwrap_or_else(T::zero))?;
     }
 
-    next_number
+    Ok(next_number)
 }
 
-pub fn extrapolate_preceeding_number(sequences: &[Vec<i32>]) -> i32 {
-    let mut preceeding_number = 0;
+pub fn extrapolate_preceeding_number<T: SequenceNumber>(sequences: &[Vec<T>]) -> Result<T, SequenceError> {
+    let mut preceeding_number = T::zero();
     for sequence in sequences.iter().rev() {
-        preceeding_number   = sequence.first().copied().unwrap_or_default() - preceeding_number;
+        preceeding_number = checked_difference(&sequence.first().cloned().unwrap_or_else(T::zero), &preceeding_number)?;
     }
 
-    preceeding_number
+    Ok(preceeding_number)
 }
 
+#[allow(dead_code)]
+pub fn extend_sequence<T: SequenceNumber>(sequence: &[T], steps: usize, direction: Direction) -> Result<ExtendedSequence<T>, SequenceError> {
+    let mut difference_sequences = calculate_complete_difference_sequences(sequence)?;
+    let bottom_level = difference_sequences.len() - 1;
+
+    for _ in 0..steps {
+        match direction {
+            Direction::Forward => {
+                difference_sequences[bottom_level].push(T::zero());
+                for level in (0..bottom_level).rev() {
+                    let last_number = difference_sequences[level].last().cloned().unwrap_or_else(T::zero);
+                    let next_number = checked_sum(&last_number, &difference_sequences[level + 1][difference_sequences[level + 1].len() - 1])?;
+                    difference_sequences[level].push(next_number);
+                }
+            }
+            Direction::Backward => {
+                difference_sequences[bottom_level].insert(0, T::zero());
+                for level in (0..bottom_level).rev() {
+                    let first_number = difference_sequences[level].first().cloned().unwrap_or_else(T::zero);
+                    let preceeding_number = checked_difference(&first_number, &difference_sequences[level + 1][0])?;
+                    difference_sequences[level].insert(0, preceeding_number);
+                }
+            }
+        }
+    }
+
+    Ok(ExtendedSequence {
+        sequence: difference_sequences[0].clone(),
+        difference_sequences,
+    })
+}
 
-pub fn calculate_complete_difference_sequences(sequence: &[i32]) -> Vec<Vec<i32>> {
+pub fn calculate_complete_difference_sequences<T: SequenceNumber>(sequence: &[T]) -> Result<Vec<Vec<T>>, SequenceError> {
     let mut sequences = vec![sequence.to_vec()];
-    let mut current_sequence = sequence.to_vec();
 
-    while current_sequence.iter().any(|&x| x != 0) {
-        current_sequence = calculate_differences(&current_sequence);
-        sequences.push(current_sequence.clone());
+    while let Some(current_sequence) = sequences.last().filter(|current| current.iter().any(|x| !x.is_zero())) {
+        let differences = calculate_differences(current_sequence)?;
+        sequences.push(differences);
     }
 
-    sequences
+    Ok(sequences)
 }
 
-pub fn calculate_differences(sequence: &[i32]) -> Vec<i32> {
+pub fn calculate_differences<T: SequenceNumber>(sequence: &[T]) -> Result<Vec<T>, SequenceError> {
     sequence.windows(2)
-        .map(|window| window[1] - window[0])
+        .map(|window| checked_difference(&window[1], &window[0]))
         .collect()
 }
 
 #[cfg(test)]
 mod tests{
+    use num::{BigInt, BigRational};
     use crate::day_9::parser::parse_sequences_from_file;
     use super::*;
 
@@ -69,8 +165,8 @@ mod tests{
     fn test_calculate_differences(){
         let expected_differences = vec![3,3,3,3,3];
 
-        let sequences_to_analyze = parse_sequences_from_file("resources/input_day_9_test.txt").unwrap();
-        let calculated_differences = calculate_differences(sequences_to_analyze.first().unwrap());
+        let sequences_to_analyze = parse_sequences_from_file::<i32>("resources/input_day_9_test.txt").unwrap();
+        let calculated_differences = calculate_differences(sequences_to_analyze.first().unwrap()).unwrap();
 
         assert_eq!(calculated_differences, expected_differences);
     }
@@ -81,8 +177,8 @@ mod tests{
         let expected_differences_1 = vec![3,3,3,3,3];
         let expected_differences_2 = vec![0,0,0,0];
 
-        let sequences_to_analyze = parse_sequences_from_file("resources/input_day_9_test.txt").unwrap();
-        let calculated_sequences = calculate_complete_difference_sequences(sequences_to_analyze.first().unwrap());
+        let sequences_to_analyze = parse_sequences_from_file::<i32>("resources/input_day_9_test.txt").unwrap();
+        let calculated_sequences = calculate_complete_difference_sequences(sequences_to_analyze.first().unwrap()).unwrap();
 
         assert_eq!(calculated_sequences.len(),3);
         assert!(calculated_sequences.contains(&original_sequence));
@@ -106,9 +202,9 @@ mod tests{
     }
 
     fn test_extrapolate_next_number_helper(test_sequence: usize, expected_result: i32){
-        let sequences_to_analyze = parse_sequences_from_file("resources/input_day_9_test.txt").unwrap();
-        let calculated_sequences = calculate_complete_difference_sequences(&sequences_to_analyze[test_sequence]);
-        let extrapolated_number = extrapolate_next_number(&calculated_sequences);
+        let sequences_to_analyze = parse_sequences_from_file::<i32>("resources/input_day_9_test.txt").unwrap();
+        let calculated_sequences = calculate_complete_difference_sequences(&sequences_to_analyze[test_sequence]).unwrap();
+        let extrapolated_number = extrapolate_next_number(&calculated_sequences).unwrap();
         assert_eq!(extrapolated_number, expected_result);
      }
 
@@ -125,33 +221,132 @@ mod tests{
     }
 
     fn test_extrapolate_preceeding_number_helper(test_sequence: usize, expected_result: i32){
-        let sequences_to_analyze = parse_sequences_from_file("resources/input_day_9_test.txt").unwrap();
-        let calculated_sequences = calculate_complete_difference_sequences(&sequences_to_analyze[test_sequence]);
-        let extrapolated_number = extrapolate_preceeding_number(&calculated_sequences);
+        let sequences_to_analyze = parse_sequences_from_file::<i32>("resources/input_day_9_test.txt").unwrap();
+        let calculated_sequences = calculate_complete_difference_sequences(&sequences_to_analyze[test_sequence]).unwrap();
+        let extrapolated_number = extrapolate_preceeding_number(&calculated_sequences).unwrap();
         assert_eq!(extrapolated_number, expected_result);
     }
 
     #[test]
     fn test_analyze_and_predict_next_numbers(){
         let expected_numbers = vec![18,28,68];
-        let sequences_to_analyze = parse_sequences_from_file("resources/input_day_9_test.txt").unwrap();
-        let next_numbers = analyze_and_predict_next_numbers(&sequences_to_analyze);
+        let sequences_to_analyze = parse_sequences_from_file::<i32>("resources/input_day_9_test.txt").unwrap();
+        let next_numbers = analyze_and_predict_next_numbers(&sequences_to_analyze).unwrap();
         assert_eq!(next_numbers, expected_numbers);
     }
 
     #[test]
     fn test_analyze_and_predict_preceeding_numbers(){
         let expected_numbers = vec![-3,0,5];
-        let sequences_to_analyze = parse_sequences_from_file("resources/input_day_9_test.txt").unwrap();
-        let preceeding_numbers = analyze_and_predict_preceding_numbers(&sequences_to_analyze);
+        let sequences_to_analyze = parse_sequences_from_file::<i32>("resources/input_day_9_test.txt").unwrap();
+        let preceeding_numbers = analyze_and_predict_preceding_numbers(&sequences_to_analyze).unwrap();
         assert_eq!(preceeding_numbers, expected_numbers);
     }
 
     #[test]
     fn test_sum_of_predictions(){
-        let sequences_to_analyze = parse_sequences_from_file("resources/input_day_9_test.txt").unwrap();
-        let next_numbers = analyze_and_predict_next_numbers(&sequences_to_analyze);
-        let sum_of_extrapolated_numbers = sum_of_predictions(&next_numbers);
+        let sequences_to_analyze = parse_sequences_from_file::<i32>("resources/input_day_9_test.txt").unwrap();
+        let next_numbers = analyze_and_predict_next_numbers(&sequences_to_analyze).unwrap();
+        let sum_of_extrapolated_numbers = sum_of_predictions(&next_numbers).unwrap();
         assert_eq!(sum_of_extrapolated_numbers, 114);
     }
+
+    #[test]
+    fn test_extend_sequence_forward(){
+        let sequences_to_analyze = parse_sequences_from_file::<i32>("resources/input_day_9_test.txt").unwrap();
+        let extended = extend_sequence(&sequences_to_analyze[0], 3, Direction::Forward).unwrap();
+
+        assert_eq!(extended.sequence, vec![0, 3, 6, 9, 12, 15, 18, 21, 24]);
+        assert_eq!(extended.difference_sequences, vec![vec![0, 3, 6, 9, 12, 15, 18, 21, 24], vec![3; 8], vec![0; 7]]);
+    }
+
+    #[test]
+    fn test_extend_sequence_backward(){
+        let sequences_to_analyze = parse_sequences_from_file::<i32>("resources/input_day_9_test.txt").unwrap();
+        let extended = extend_sequence(&sequences_to_analyze[2], 2, Direction::Backward).unwrap();
+
+        assert_eq!(extended.sequence, vec![-4, 5, 10, 13, 16, 21, 30, 45]);
+        assert_eq!(extended.difference_sequences[1], vec![9, 5, 3, 3, 5, 9, 15]);
+    }
+
+    #[test]
+    fn test_extend_sequence_matches_single_extrapolation(){
+        let sequences_to_analyze = parse_sequences_from_file::<i32>("resources/input_day_9_test.txt").unwrap();
+
+        for sequence in &sequences_to_analyze {
+            let forward = extend_sequence(sequence, 1, Direction::Forward).unwrap();
+            let backward = extend_sequence(sequence, 1, Direction::Backward).unwrap();
+            let difference_sequences = calculate_complete_difference_sequences(sequence).unwrap();
+
+            assert_eq!(*forward.sequence.last().unwrap(), extrapolate_next_number(&difference_sequences).unwrap());
+            assert_eq!(backward.sequence[0], extrapolate_preceeding_number(&difference_sequences).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_predict_neighbours_matches_difference_pyramid(){
+        let sequences_to_analyze = parse_sequences_from_file::<i32>("resources/input_day_9_test.txt").unwrap();
+        let neighbours = analyze_and_predict_neighbours(&sequences_to_analyze).unwrap();
+        let next_numbers = analyze_and_predict_next_numbers(&sequences_to_analyze).unwrap();
+        let preceding_numbers = analyze_and_predict_preceding_numbers(&sequences_to_analyze).unwrap();
+
+        assert_eq!(neighbours, vec![(18, -3), (28, 0), (68, 5)]);
+        assert_eq!(neighbours, next_numbers.into_iter().zip(preceding_numbers).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_predict_neighbours_edge_cases(){
+        assert_eq!(predict_neighbours(0, &[0, 0, 0]).unwrap(), (0, 0));
+        assert_eq!(predict_neighbours(0, &[7, 7]).unwrap(), (7, 7));
+        assert!(matches!(predict_neighbours::<i32>(0, &[]), Err(SequenceError::NotPolynomial { .. })));
+        assert!(matches!(predict_neighbours(4, &[5]), Err(SequenceError::NotPolynomial { sequence_index: 4, .. })));
+        assert!(matches!(predict_neighbours(0, &[i32::MAX - 2, i32::MAX - 1, i32::MAX]), Err(SequenceError::Overflow)));
+    }
+
+    #[test]
+    fn test_analyze_and_predict_rejects_non_polynomial_sequences(){
+        let sequences_to_analyze = parse_sequences_from_file::<i64>("resources/input_day_9_test_b.txt").unwrap();
+
+        match analyze_and_predict_next_numbers(&sequences_to_analyze) {
+            Err(SequenceError::NotPolynomial { sequence_index, classification }) => {
+                assert_eq!(sequence_index, 1);
+                assert!(!classification.is_polynomial());
+            }
+            result => panic!("Expected non-polynomial error, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_analyze_and_predict_next_numbers_for_wide_types(){
+        let expected_numbers = vec![18,28,68];
+        assert_eq!(analyze_and_predict_next_numbers(&parse_sequences_from_file::<i64>("resources/input_day_9_test.txt").unwrap()).unwrap(), expected_numbers);
+        assert_eq!(analyze_and_predict_next_numbers(&parse_sequences_from_file::<i128>("resources/input_day_9_test.txt").unwrap()).unwrap(), vec![18,28,68]);
+
+        let big_numbers = analyze_and_predict_next_numbers(&parse_sequences_from_file::<BigInt>("resources/input_day_9_test.txt").unwrap()).unwrap();
+        assert_eq!(big_numbers, expected_numbers.into_iter().map(BigInt::from).collect::<Vec<BigInt>>());
+    }
+
+    #[test]
+    fn test_analyze_and_predict_numbers_for_rationals(){
+        let sequences = vec![vec![BigRational::new(1.into(), 2.into()), BigRational::from_integer(1.into()), BigRational::new(3.into(), 2.into())]];
+        let next_numbers = analyze_and_predict_next_numbers(&sequences).unwrap();
+        let preceding_numbers = analyze_and_predict_preceding_numbers(&sequences).unwrap();
+
+        assert_eq!(next_numbers, vec![BigRational::from_integer(2.into())]);
+        assert_eq!(preceding_numbers, vec![BigRational::from_integer(0.into())]);
+    }
+
+    #[test]
+    fn test_overflow_is_reported(){
+        assert!(matches!(analyze_and_predict_next_numbers(&[vec![i32::MAX - 2, i32::MAX - 1, i32::MAX]]), Err(SequenceError::Overflow)));
+        assert!(calculate_differences(&[i32::MIN, i32::MAX]).is_err());
+        assert_eq!(analyze_and_predict_next_numbers(&[vec![i64::from(i32::MAX) - 2, i64::from(i32::MAX) - 1, i64::from(i32::MAX)]]).unwrap(), vec![i64::from(i32::MAX) + 1]);
+    }
+
+    #[test]
+    fn test_big_integers_do_not_overflow(){
+        let steep_sequence: Vec<BigInt> = (0..30u32).map(|n| BigInt::from(n).pow(20)).collect();
+        let next_number = analyze_and_predict_next_numbers(&[steep_sequence]).unwrap();
+        assert_eq!(next_number, vec![BigInt::from(30).pow(20)]);
+    }
 }
@@ -0,0 +1,108 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Lines};
+use std::iter::Enumerate;
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::thread;
+use anyhow::{Context, Result};
+use crate::day_9::missing_readings::fill_sequence;
+use crate::day_9::number::{checked_sum, FromRational, SequenceNumber, ToRational};
+use crate::day_9::parser::parse_readings_line;
+use crate::day_9::sequence_analysis::predict_neighbours;
+
+const STREAM_BATCH_LINES: usize = 1 << 12;
+
+type NumberedLines = Enumerate<Lines<BufReader<File>>>;
+
+pub fn stream_prediction_sums<T>(file_path: &str, threads: usize) -> Result<(T, T)>
+    where
+        T: SequenceNumber + ToRational + FromRational + FromStr + Send,
+        T::Err: std::error::Error + Send + Sync + 'static
+{
+    let file = File::open(file_path).context("Failed to open file")?;
+    let lines = Mutex::new(io::BufReader::new(file).lines().enumerate());
+
+    let partial_sums = thread::scope(|scope| {
+        let handles: Vec<_> = (0..threads.max(1))
+            .map(|_| scope.spawn(|| sum_batches::<T>(&lines)))
+            .collect();
+
+        handles.into_iter()
+            .map(|handle| handle.join().expect("Streaming thread panicked"))
+            .collect::<Result<Vec<(T, T)>>>()
+    })?;
+
+    partial_sums.iter().try_fold((T::zero(), T::zero()), |(next_sum, preceding_sum), (next, preceding)| {
+        Ok((checked_sum(&next_sum, next)?, checked_sum(&preceding_sum, preceding)?))
+    })
+}
+
+fn sum_batches<T>(lines: &Mutex<NumberedLines>) -> Result<(T, T)>
+    where
+        T: SequenceNumber + ToRational + FromRational + FromStr,
+        T::Err: std::error::Error + Send + Sync + 'static
+{
+    let mut next_sum = T::zero();
+    let mut preceding_sum = T::zero();
+
+    loop {
+        let batch = next_batch(lines)?;
+        if batch.is_empty() {
+            return Ok((next_sum, preceding_sum));
+        }
+
+        for (sequence_index, line) in batch {
+            let readings = parse_readings_line::<T>(&line)
+                .with_context(|| format!("Failed to parse line {}", sequence_index + 1))?;
+            let sequence = fill_sequence(sequence_index, &readings)?;
+            let (next, preceding) = predict_neighbours(sequence_index, &sequence)?;
+
+            next_sum = checked_sum(&next_sum, &next)?;
+            preceding_sum = checked_sum(&preceding_sum, &preceding)?;
+        }
+    }
+}
+
+fn next_batch(lines: &Mutex<NumberedLines>) -> Result<Vec<(usize, String)>> {
+    let mut lines = lines.lock().expect("Line reader lock poisoned");
+    lines.by_ref()
+        .take(STREAM_BATCH_LINES)
+        .map(|(index, line)| line.map(|line| (index, line)).context("Failed to read line"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use rstest::rstest;
+    use num::BigInt;
+    use super::*;
+    use crate::day_9::errors::SequenceError;
+
+    #[rstest]
+    #[case(1)]
+    #[case(4)]
+    fn test_stream_prediction_sums(#[case] threads: usize) {
+        assert_eq!(stream_prediction_sums::<i64>("resources/input_day_9_test.txt", threads).unwrap(), (114, 2));
+        assert_eq!(stream_prediction_sums::<BigInt>("resources/input_day_9_test.txt", threads).unwrap(), (BigInt::from(114), BigInt::from(2)));
+    }
+
+    #[test]
+    fn test_stream_prediction_sums_over_many_batches() {
+        let file_path = std::env::temp_dir().join(format!("aoc_2023_day_9_stream_{}.txt", std::process::id()));
+        let repetitions = 3 * STREAM_BATCH_LINES + 7;
+        let test_input = format!("{}\n", fs::read_to_string("resources/input_day_9_test.txt").unwrap().trim_end());
+        fs::write(&file_path, test_input.repeat(repetitions)).unwrap();
+
+        let sums = stream_prediction_sums::<i64>(file_path.to_str().unwrap(), 3);
+        fs::remove_file(&file_path).unwrap();
+
+        assert_eq!(sums.unwrap(), (114 * repetitions as i64, 2 * repetitions as i64));
+    }
+
+    #[test]
+    fn test_stream_reports_undetermined_gaps() {
+        let error = stream_prediction_sums::<i64>("resources/input_day_9_test_c.txt", 2).unwrap_err();
+        assert!(matches!(error.downcast_ref::<SequenceError>(), Some(SequenceError::UndeterminedGap { sequence_index: 2, .. })));
+    }
+}
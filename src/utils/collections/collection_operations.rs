@@ -26,6 +26,7 @@ impl IntoIsizeTuple for (isize, usize) {
     }
 }
 
+#[allow(dead_code)]
 pub fn subtract_tuples<T: IntoIsizeTuple>(a: T, b: T) -> (isize, isize) {
     let a = a.into_isize_tuple();
     let b = b.into_isize_tuple();
@@ -43,15 +44,3 @@ pub fn add_tuples<T: IntoIsizeTuple, U: IntoIsizeTuple>(a: T, b: U) -> (isize, i
 
     (dx, dy)
 }
-
-pub fn choose_tuple(v: &[(usize, usize)]) -> (usize, usize) {
-    if v.len() != 2 {
-        panic!("Expected a vector with exactly two elements");
-    }
-
-    if v[0] < v[1] {
-        v[1]
-    } else {
-        v[0]
-    }
-}
\ No newline at end of file
@@ -5,6 +5,7 @@ pub fn read_file<P: AsRef<Path>>(file_path: P) -> io::Result<String> {
     fs::read_to_string(file_path)
 }
 
+#[allow(dead_code)]
 pub fn print_grid(grid: &Vec<Vec<char>>) {
     for row in grid {
         for &character in row {
@@ -14,6 +15,7 @@ pub fn print_grid(grid: &Vec<Vec<char>>) {
     }
 }
 
+#[allow(dead_code)]
 pub fn print_grid_for_tuples(grid: &Vec<Vec<(char,usize,usize)>>) {
     for row in grid {
         for &tuple in row {
@@ -1,7 +1,26 @@
-pub fn greatest_common_divisor(a: usize, b: usize) -> usize {
-    if b == 0 { a } else { greatest_common_divisor(b, a % b) }
+pub fn extended_greatest_common_divisor(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (divisor, x, y) = extended_greatest_common_divisor(b, a % b);
+        (divisor, y, x - (a / b) * y)
+    }
 }
 
-pub fn least_common_multiple(a: usize, b: usize) -> usize {
-    a / greatest_common_divisor(a, b) * b
+pub fn combine_congruences(first: (i128, i128), second: (i128, i128)) -> Option<(i128, i128)> {
+    let (first_residue, first_modulus) = first;
+    let (second_residue, second_modulus) = second;
+    let (divisor, first_coefficient, _) = extended_greatest_common_divisor(first_modulus, second_modulus);
+    let residue_difference = second_residue - first_residue;
+
+    if residue_difference % divisor != 0 {
+        return None;
+    }
+
+    let reduced_modulus = second_modulus / divisor;
+    let combined_modulus = first_modulus * reduced_modulus;
+    let multiplier = (residue_difference / divisor % reduced_modulus * first_coefficient).rem_euclid(reduced_modulus);
+    let combined_residue = (first_residue + first_modulus * multiplier).rem_euclid(combined_modulus);
+
+    Some((combined_residue, combined_modulus))
 }